@@ -0,0 +1,135 @@
+//! Utilities for caching values across calls within a single game tick.
+//!
+//! Game objects are only valid for the tick they were retrieved in, and most
+//! derived data goes stale between ticks as well; these helpers tie the
+//! lifetime of a cached value to [`game::time`].
+//!
+//! [`game::time`]: crate::game::time
+
+use crate::game;
+
+/// A value which is computed at most once per game tick.
+///
+/// The cached value is recomputed whenever the tick differs from the one it
+/// was computed on, in either direction - so a global reset which appears to
+/// move time backward, or a jump of multiple ticks, can't leave a stale value
+/// in place.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::{cache::TickCache, game};
+///
+/// let mut creep_count = TickCache::new();
+///
+/// let count = *creep_count.get_or_init(|| game::creeps().keys().count());
+/// // later in the same tick, this won't call `game::creeps` again
+/// let count_again = *creep_count.get_or_init(|| game::creeps().keys().count());
+/// assert_eq!(count, count_again);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TickCache<T> {
+    cached: Option<(u32, T)>,
+}
+
+impl<T> Default for TickCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TickCache<T> {
+    /// Create an empty `TickCache`.
+    #[inline]
+    pub const fn new() -> Self {
+        TickCache { cached: None }
+    }
+
+    /// Get the value cached for the current tick, calling `f` to compute it if
+    /// it hasn't yet been computed this tick.
+    pub fn get_or_init(&mut self, f: impl FnOnce() -> T) -> &T {
+        self.get_or_init_at(game::time(), f)
+    }
+
+    /// Get the value cached for the current tick, if it has already been
+    /// computed this tick.
+    pub fn get(&self) -> Option<&T> {
+        self.get_at(game::time())
+    }
+
+    /// Clear any cached value, forcing it to be recomputed on the next call to
+    /// [`TickCache::get_or_init`].
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cached = None;
+    }
+
+    fn get_at(&self, tick: u32) -> Option<&T> {
+        match &self.cached {
+            Some((cached_tick, value)) if *cached_tick == tick => Some(value),
+            _ => None,
+        }
+    }
+
+    fn get_or_init_at(&mut self, tick: u32, f: impl FnOnce() -> T) -> &T {
+        match &mut self.cached {
+            Some((cached_tick, _)) if *cached_tick == tick => {}
+            cached => *cached = Some((tick, f())),
+        }
+
+        // the match above always leaves a value cached for this tick
+        &self.cached.as_ref().expect("expected value to be cached").1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TickCache;
+
+    #[test]
+    fn recomputes_only_when_tick_changes() {
+        let mut cache = TickCache::new();
+        let mut calls = 0;
+
+        assert_eq!(cache.get_at(100), None);
+
+        assert_eq!(
+            *cache.get_or_init_at(100, || {
+                calls += 1;
+                "first"
+            }),
+            "first"
+        );
+        assert_eq!(
+            *cache.get_or_init_at(100, || {
+                calls += 1;
+                "second"
+            }),
+            "first"
+        );
+        assert_eq!(calls, 1);
+        assert_eq!(cache.get_at(100), Some(&"first"));
+
+        assert_eq!(
+            *cache.get_or_init_at(101, || {
+                calls += 1;
+                "third"
+            }),
+            "third"
+        );
+        assert_eq!(calls, 2);
+        assert_eq!(cache.get_at(100), None);
+    }
+
+    #[test]
+    fn recomputes_when_tick_moves_backward() {
+        let mut cache = TickCache::new();
+
+        assert_eq!(*cache.get_or_init_at(500, || 1), 1);
+        assert_eq!(*cache.get_or_init_at(20, || 2), 2);
+        assert_eq!(*cache.get_or_init_at(20, || 3), 2);
+
+        cache.clear();
+        assert_eq!(*cache.get_or_init_at(20, || 4), 4);
+    }
+}
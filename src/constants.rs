@@ -151,12 +151,13 @@ pub mod control {
     pub use super::{
         extra::{CONTROLLER_DOWNGRADE_PROGRESS_RATIO, CONTROLLER_SIGN_MAX_LENGTH},
         numbers::{
-            controller_downgrade, controller_levels, CONTROLLER_ATTACK_BLOCKED_UPGRADE,
-            CONTROLLER_CLAIM_DOWNGRADE, CONTROLLER_DOWNGRADE_RESTORE,
-            CONTROLLER_DOWNGRADE_SAFEMODE_THRESHOLD, CONTROLLER_MAX_UPGRADE_PER_TICK,
-            CONTROLLER_NUKE_BLOCKED_UPGRADE, CONTROLLER_RESERVE, CONTROLLER_RESERVE_MAX,
-            GCL_MULTIPLY, GCL_NOVICE, GCL_POW, SAFE_MODE_COOLDOWN, SAFE_MODE_COST,
-            SAFE_MODE_DURATION, SIGN_PLANNED_AREA, SYSTEM_USERNAME,
+            controller_downgrade, controller_levels, safe_mode_cooldown, safe_mode_duration,
+            safe_mode_threshold, upgrade_energy_per_tick, upgrade_progress_per_tick,
+            CONTROLLER_ATTACK_BLOCKED_UPGRADE, CONTROLLER_CLAIM_DOWNGRADE,
+            CONTROLLER_DOWNGRADE_RESTORE, CONTROLLER_DOWNGRADE_SAFEMODE_THRESHOLD,
+            CONTROLLER_MAX_UPGRADE_PER_TICK, CONTROLLER_NUKE_BLOCKED_UPGRADE, CONTROLLER_RESERVE,
+            CONTROLLER_RESERVE_MAX, GCL_MULTIPLY, GCL_NOVICE, GCL_POW, SAFE_MODE_COOLDOWN,
+            SAFE_MODE_COST, SAFE_MODE_DURATION, SIGN_PLANNED_AREA, SYSTEM_USERNAME,
         },
     };
 }
@@ -203,7 +204,7 @@ pub mod source {
 /// [market]: crate::game::market
 pub mod market {
     pub use super::{
-        extra::MARKET_MAX_DEALS_PER_TICK,
+        extra::{terminal_send_cost, MARKET_MAX_DEALS_PER_TICK},
         numbers::{
             MARKET_FEE, MARKET_MAX_ORDERS, MARKET_ORDER_LIFE_TIME, TERMINAL_CAPACITY,
             TERMINAL_COOLDOWN, TERMINAL_MIN_SEND, TERMINAL_SEND_COST,
@@ -218,8 +219,8 @@ pub mod spawn {
     pub use super::{
         extra::SPAWN_NAME_MAX_LENGTH,
         numbers::{
-            extension_energy_capacity, CREEP_SPAWN_TIME, ENERGY_REGEN_TIME, MAX_CREEP_SIZE,
-            SPAWN_ENERGY_CAPACITY, SPAWN_ENERGY_START, SPAWN_RENEW_RATIO,
+            extension_energy_capacity, should_prespawn, CREEP_SPAWN_TIME, ENERGY_REGEN_TIME,
+            MAX_CREEP_SIZE, SPAWN_ENERGY_CAPACITY, SPAWN_ENERGY_START, SPAWN_RENEW_RATIO,
         },
     };
 }
@@ -229,8 +230,9 @@ pub mod spawn {
 /// [`StructureTower`]: crate::objects::StructureTower
 pub mod tower {
     pub use super::numbers::{
-        TOWER_CAPACITY, TOWER_ENERGY_COST, TOWER_FALLOFF, TOWER_FALLOFF_RANGE, TOWER_OPTIMAL_RANGE,
-        TOWER_POWER_ATTACK, TOWER_POWER_HEAL, TOWER_POWER_REPAIR,
+        tower_power_at_range, TOWER_CAPACITY, TOWER_ENERGY_COST, TOWER_FALLOFF,
+        TOWER_FALLOFF_RANGE, TOWER_OPTIMAL_RANGE, TOWER_POWER_ATTACK, TOWER_POWER_HEAL,
+        TOWER_POWER_REPAIR,
     };
 }
 
@@ -305,3 +305,27 @@ pub const SPAWN_NAME_MAX_LENGTH: u32 = 100;
 ///
 /// [`StructureTerminal`]: crate::objects::StructureTerminal
 pub const TERMINAL_SEND_COST_SCALE: u32 = 30;
+
+/// Calculate the energy cost of sending `amount` of a resource with a
+/// [`StructureTerminal`] across `range` rooms, matching
+/// [`calc_transaction_cost`] without a call into the game.
+///
+/// The range should be the continuous linear distance between the rooms, as
+/// returned by [`get_room_linear_distance`] with `continuous` set.
+///
+/// ```
+/// use screeps::constants::terminal_send_cost;
+///
+/// assert_eq!(terminal_send_cost(1000, 0), 0);
+/// assert_eq!(terminal_send_cost(1000, 10), 284);
+/// ```
+///
+/// [Code reference](https://github.com/screeps/engine/blob/c682c00b058de21e927c3a6c42fadb34c9745767/src/utils.js#L658)
+///
+/// [`StructureTerminal`]: crate::objects::StructureTerminal
+/// [`calc_transaction_cost`]: crate::game::market::calc_transaction_cost
+/// [`get_room_linear_distance`]: crate::game::map::get_room_linear_distance
+pub fn terminal_send_cost(amount: u32, range: u32) -> u32 {
+    let scale = -(range as f64) / TERMINAL_SEND_COST_SCALE as f64;
+    (amount as f64 * (1.0 - scale.exp())).ceil() as u32
+}
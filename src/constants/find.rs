@@ -331,3 +331,24 @@ typesafe_find_constants! {
 typesafe_find_constants! {
     pub struct REACTORS = (Find::Reactors, Reactor, Into::into);
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn power_creep_find_codes() {
+        assert_eq!(POWER_CREEPS.find_code(), Find::PowerCreeps);
+        assert_eq!(MY_POWER_CREEPS.find_code(), Find::MyPowerCreeps);
+        assert_eq!(HOSTILE_POWER_CREEPS.find_code(), Find::HostilePowerCreeps);
+
+        assert_eq!(Find::PowerCreeps as u16, 119);
+        assert_eq!(Find::MyPowerCreeps as u16, 120);
+        assert_eq!(Find::HostilePowerCreeps as u16, 121);
+
+        assert_eq!(
+            RoomObject::HostilePowerCreeps.find_code(),
+            HOSTILE_POWER_CREEPS.find_code()
+        );
+    }
+}
@@ -79,7 +79,12 @@ macro_rules! typesafe_look_constants {
     );
 }
 
+/// A typed `LOOK_*` constant, determining the type of object returned by
+/// [`Room::look_for_at`] and related functions.
+///
+/// [`Room::look_for_at`]: crate::objects::Room::look_for_at
 pub trait LookConstant {
+    /// The type of object returned when looking for this constant.
     type Item;
 
     fn convert_and_check_item(reference: JsValue) -> Self::Item;
@@ -272,3 +277,52 @@ extern "C" {
     #[wasm_bindgen(method, getter = reactor)]
     fn reactor(this: &JsLookResult) -> Reactor;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local::Position;
+
+    fn look_code_for<T: LookConstant<Item = I>, I>(_ty: T) -> Look {
+        T::look_code()
+    }
+
+    #[test]
+    fn every_look_has_a_typed_constant() {
+        let codes = [
+            look_code_for::<_, Creep>(CREEPS),
+            look_code_for::<_, Resource>(ENERGY),
+            look_code_for::<_, Resource>(RESOURCES),
+            look_code_for::<_, Source>(SOURCES),
+            look_code_for::<_, Mineral>(MINERALS),
+            look_code_for::<_, Deposit>(DEPOSITS),
+            look_code_for::<_, StructureObject>(STRUCTURES),
+            look_code_for::<_, Flag>(FLAGS),
+            look_code_for::<_, ConstructionSite>(CONSTRUCTION_SITES),
+            look_code_for::<_, Nuke>(NUKES),
+            look_code_for::<_, Terrain>(TERRAIN),
+            look_code_for::<_, Tombstone>(TOMBSTONES),
+            look_code_for::<_, PowerCreep>(POWER_CREEPS),
+            look_code_for::<_, Ruin>(RUINS),
+        ];
+
+        let seasonal = [
+            Look::ScoreContainers,
+            Look::ScoreCollectors,
+            Look::SymbolContainers,
+            Look::SymbolDecoders,
+            Look::Reactors,
+            Look::__Invalid,
+        ];
+        for look in enum_iterator::all::<Look>().filter(|look| !seasonal.contains(look)) {
+            assert!(codes.contains(&look), "no typed constant for {look:?}");
+        }
+    }
+
+    #[test]
+    fn look_for_at_returns_typed_items() {
+        // only needs to compile; looking requires the game environment
+        let _: fn(&Room, RUINS, &Position) -> Vec<Ruin> = Room::look_for_at;
+        let _: fn(&Room, TERRAIN, &Position) -> Vec<Terrain> = Room::look_for_at;
+    }
+}
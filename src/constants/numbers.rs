@@ -1,5 +1,8 @@
 //! Plain data constants and functions returning plain data.
-use super::types::{ResourceType, StructureType};
+use super::{
+    small_enums::{Part, Terrain},
+    types::{Boost, ResourceType, StructureType},
+};
 
 // OK and ERR_* defined in ReturnCode in `small_enums.rs`
 
@@ -171,6 +174,144 @@ pub const CREEP_SPAWN_TIME: u32 = 3;
 /// [`StructureSpawn.renewCreep`]: https://docs.screeps.com/api/#StructureSpawn.renewCreep
 pub const SPAWN_RENEW_RATIO: f32 = 1.2;
 
+/// Ticks taken to spawn a creep with the given body, before power creep
+/// effects; [`CREEP_SPAWN_TIME`] for each part.
+#[inline]
+pub const fn spawn_duration(body: &[Part]) -> u32 {
+    body.len() as u32 * CREEP_SPAWN_TIME
+}
+
+/// Ticks added to a creep's timer by each call to
+/// [`StructureSpawn::renew_creep`], `floor(600/body_size)`, as described on
+/// [`SPAWN_RENEW_RATIO`].
+///
+/// Returns 0 for an empty body.
+///
+/// [`StructureSpawn::renew_creep`]: crate::objects::StructureSpawn::renew_creep
+#[inline]
+pub const fn renew_ticks_per_call(body_size: u32) -> u32 {
+    // SPAWN_RENEW_RATIO * CREEP_LIFE_TIME / CREEP_SPAWN_TIME, kept in integers
+    match (CREEP_LIFE_TIME * 6 / 5 / CREEP_SPAWN_TIME).checked_div(body_size) {
+        Some(ticks) => ticks,
+        None => 0,
+    }
+}
+
+/// Whether it's time to start spawning a replacement for a creep with
+/// `ticks_to_live` left, so that the replacement finishes spawning and
+/// travels into place as the old creep dies.
+///
+/// That's once `ticks_to_live <= spawn_ticks + travel_ticks`, where
+/// `spawn_ticks` can be found with [`spawn_duration`]; spawning at exactly
+/// that point leaves no ticks without a creep in place.
+///
+/// ```
+/// use screeps::constants::should_prespawn;
+///
+/// assert!(!should_prespawn(61, 30, 30));
+/// assert!(should_prespawn(60, 30, 30));
+/// ```
+#[inline]
+pub const fn should_prespawn(ticks_to_live: u32, spawn_ticks: u32, travel_ticks: u32) -> bool {
+    ticks_to_live <= spawn_ticks.saturating_add(travel_ticks)
+}
+
+/// Fatigue generated by each body part which isn't a [`Part::Move`] when a
+/// creep moves onto a tile of the given terrain, or `None` for a wall without
+/// a road through it.
+///
+/// Empty [`Part::Carry`] parts don't count, and generate no fatigue. Each
+/// move part removes 2 fatigue per tick ([source]).
+///
+/// [source]: https://github.com/screeps/engine/blob/b2ac4720abe399837b0ba38712aaadfd4a9e9a7e/src/processor/intents/movement.js
+#[inline]
+pub const fn terrain_fatigue_per_part(terrain: Terrain, has_road: bool) -> Option<u32> {
+    match (terrain, has_road) {
+        (_, true) => Some(1),
+        (Terrain::Plain, false) => Some(2),
+        (Terrain::Swamp, false) => Some(10),
+        (Terrain::Wall, false) => None,
+    }
+}
+
+/// Fatigue left on a creep after it moves onto a tile of the given terrain,
+/// with `move_parts` move parts and `other_parts` parts generating fatigue -
+/// that is, parts other than move parts and empty carry parts.
+///
+/// This is the fatigue the other parts generate, less the 2 fatigue each move
+/// part removes on the tick of the move. A result of 0 means the creep can
+/// move again on the next tick, so moves unhindered over this terrain. Walls
+/// without a road return `u32::MAX`, as no creep can move onto them. For the
+/// number of ticks each step takes, see [`terrain_move_ticks`].
+///
+/// # Example
+///
+/// ```
+/// use screeps::constants::{terrain_move_cost, Terrain};
+///
+/// // one move part per other part moves unhindered on plains, but not swamps
+/// assert_eq!(terrain_move_cost(Terrain::Plain, false, 5, 5), 0);
+/// assert_eq!(terrain_move_cost(Terrain::Swamp, false, 5, 5), 40);
+/// // one move part for every two other parts needs roads
+/// assert_eq!(terrain_move_cost(Terrain::Plain, false, 5, 10), 10);
+/// assert_eq!(terrain_move_cost(Terrain::Swamp, true, 5, 10), 0);
+/// ```
+#[inline]
+pub const fn terrain_move_cost(
+    terrain: Terrain,
+    has_road: bool,
+    move_parts: u32,
+    other_parts: u32,
+) -> u32 {
+    match terrain_fatigue_per_part(terrain, has_road) {
+        Some(per_part) => (per_part * other_parts).saturating_sub(move_parts * 2),
+        None => u32::MAX,
+    }
+}
+
+/// Ticks taken per step by a creep moving over the given terrain, with
+/// `move_parts` move parts and `other_parts` parts generating fatigue - that
+/// is, parts other than move parts and empty carry parts.
+///
+/// A result of 1 means the creep moves every tick without building up fatigue.
+/// Returns `None` if the creep can't move onto the tile at all, either because
+/// it's a wall without a road, or the creep has no move parts.
+///
+/// # Example
+///
+/// ```
+/// use screeps::constants::{terrain_move_ticks, Terrain};
+///
+/// // one move part per other part moves every tick on plains, but not swamps
+/// assert_eq!(terrain_move_ticks(Terrain::Plain, false, 5, 5), Some(1));
+/// assert_eq!(terrain_move_ticks(Terrain::Swamp, false, 5, 5), Some(5));
+/// // one move part for every two other parts needs roads
+/// assert_eq!(terrain_move_ticks(Terrain::Plain, false, 5, 10), Some(2));
+/// assert_eq!(terrain_move_ticks(Terrain::Swamp, true, 5, 10), Some(1));
+/// ```
+#[inline]
+pub const fn terrain_move_ticks(
+    terrain: Terrain,
+    has_road: bool,
+    move_parts: u32,
+    other_parts: u32,
+) -> Option<u32> {
+    let fatigue = match terrain_fatigue_per_part(terrain, has_road) {
+        Some(per_part) => per_part * other_parts,
+        None => return None,
+    };
+    if move_parts == 0 {
+        return None;
+    }
+
+    let ticks = fatigue.div_ceil(move_parts * 2);
+    if ticks > 1 {
+        Some(ticks)
+    } else {
+        Some(1)
+    }
+}
+
 /// Source energy capacity immediately after regeneration in owned and reserved
 /// rooms.
 pub const SOURCE_ENERGY_CAPACITY: u32 = 3000;
@@ -338,6 +479,31 @@ pub const CONTROLLER_DOWNGRADE_RESTORE: u32 = 100;
 /// [`Creep::attack_controller`]: crate::objects::Creep::attack_controller
 /// [`Creep::upgrade_controller`]: crate::objects::Creep::upgrade_controller
 pub const CONTROLLER_DOWNGRADE_SAFEMODE_THRESHOLD: u32 = 5000;
+
+/// The [`StructureController::ticks_to_downgrade`] value below which safe
+/// mode can't be activated for each controller level: half of
+/// [`controller_downgrade`], minus [`CONTROLLER_DOWNGRADE_SAFEMODE_THRESHOLD`].
+///
+/// Returns `Some` for levels 1-8, `None` for all others.
+///
+/// ```
+/// use screeps::constants::safe_mode_threshold;
+///
+/// assert_eq!(safe_mode_threshold(7), Some(70_000));
+/// assert_eq!(safe_mode_threshold(2), Some(0));
+/// assert_eq!(safe_mode_threshold(0), None);
+/// ```
+///
+/// [`StructureController::ticks_to_downgrade`]:
+/// crate::objects::StructureController::ticks_to_downgrade
+#[inline]
+pub const fn safe_mode_threshold(rcl: u8) -> Option<u32> {
+    match controller_downgrade(rcl) {
+        Some(ticks) => Some((ticks / 2).saturating_sub(CONTROLLER_DOWNGRADE_SAFEMODE_THRESHOLD)),
+        None => None,
+    }
+}
+
 /// Additional decay of the [`StructureController::ticks_to_downgrade`] timer
 /// caused by each claim part used per [`Creep::attack_controller`] action.
 ///
@@ -378,6 +544,77 @@ pub const SAFE_MODE_COOLDOWN: u32 = 50_000;
 /// [`Creep::generate_safe_mode`]: crate::objects::Creep::generate_safe_mode
 pub const SAFE_MODE_COST: u32 = 1000;
 
+/// The number of ticks safe mode lasts once activated; the same as
+/// [`SAFE_MODE_DURATION`].
+///
+/// ```
+/// use screeps::constants::safe_mode_duration;
+///
+/// assert_eq!(safe_mode_duration(), 20_000);
+/// ```
+#[inline]
+pub const fn safe_mode_duration() -> u32 {
+    SAFE_MODE_DURATION
+}
+
+/// The number of ticks after a safe mode activation before safe mode can be
+/// activated again; the same as [`SAFE_MODE_COOLDOWN`].
+///
+/// ```
+/// use screeps::constants::safe_mode_cooldown;
+///
+/// assert_eq!(safe_mode_cooldown(), 50_000);
+/// ```
+#[inline]
+pub const fn safe_mode_cooldown() -> u32 {
+    SAFE_MODE_COOLDOWN
+}
+
+/// Energy spent per tick by a creep with the given number of work parts
+/// using [`Creep::upgrade_controller`] on a controller at the given level.
+///
+/// At room control level 8, this is capped at
+/// [`CONTROLLER_MAX_UPGRADE_PER_TICK`]; note that this cap is shared between
+/// all creeps upgrading the same controller in a tick. Boosts don't change the
+/// energy spent, only the progress gained - see [`upgrade_progress_per_tick`].
+///
+/// [`Creep::upgrade_controller`]: crate::objects::Creep::upgrade_controller
+#[inline]
+pub const fn upgrade_energy_per_tick(work_parts: u32, controller_level: u8) -> u32 {
+    let energy = work_parts.saturating_mul(UPGRADE_CONTROLLER_POWER);
+    if controller_level >= 8 && energy > CONTROLLER_MAX_UPGRADE_PER_TICK {
+        CONTROLLER_MAX_UPGRADE_PER_TICK
+    } else {
+        energy
+    }
+}
+
+/// Controller progress gained per tick by a creep with the given number of
+/// work parts using [`Creep::upgrade_controller`] on a controller at the
+/// given level, assuming every work part carries `boost`.
+///
+/// Only [`Boost::UpgradeController`] boosts have any effect. The energy spent
+/// is capped as in [`upgrade_energy_per_tick`], and the boost multiplies
+/// the capped amount, rounding down.
+///
+/// [`Creep::upgrade_controller`]: crate::objects::Creep::upgrade_controller
+pub fn upgrade_progress_per_tick(
+    work_parts: u32,
+    boost: Option<Boost>,
+    controller_level: u8,
+) -> u32 {
+    let energy = upgrade_energy_per_tick(work_parts, controller_level);
+    match boost {
+        // multipliers are all in tenths; keep the math in integers to avoid
+        // float rounding pushing an exact result below the next integer
+        Some(Boost::UpgradeController(multiplier)) => {
+            let tenths = (multiplier * 10.0).round() as u32;
+            energy.saturating_mul(tenths) / 10
+        }
+        _ => energy,
+    }
+}
+
 /// Initial hits for tower structures; consider using the
 /// [`StructureType::initial_hits`] function.
 pub const TOWER_HITS: u32 = 3000;
@@ -418,6 +655,31 @@ pub const TOWER_FALLOFF_RANGE: u8 = 20;
 /// [source]: https://github.com/screeps/engine/blob/f02d16a44a00c35615ae227fc72a3c9a07a6a39a/src/processor/intents/towers/attack.js#L38
 pub const TOWER_FALLOFF: f64 = 0.75;
 
+/// The amount of damage, healing, or repair done by a tower action with the
+/// given base power (such as [`TOWER_POWER_ATTACK`]) at a given range, after
+/// [`TOWER_FALLOFF`] and before any power creep effects.
+///
+/// # Example
+///
+/// ```
+/// use screeps::constants::{tower_power_at_range, TOWER_POWER_ATTACK};
+///
+/// assert_eq!(tower_power_at_range(TOWER_POWER_ATTACK, 5), 600);
+/// assert_eq!(tower_power_at_range(TOWER_POWER_ATTACK, 11), 420);
+/// assert_eq!(tower_power_at_range(TOWER_POWER_ATTACK, 20), 150);
+/// assert_eq!(tower_power_at_range(TOWER_POWER_ATTACK, 40), 150);
+/// ```
+pub fn tower_power_at_range(power: u32, range: u32) -> u32 {
+    let optimal = TOWER_OPTIMAL_RANGE as u32;
+    let falloff_range = TOWER_FALLOFF_RANGE as u32;
+    let range = range.clamp(optimal, falloff_range);
+
+    let amount = power as f64;
+    let reduction =
+        amount * TOWER_FALLOFF * (range - optimal) as f64 / (falloff_range - optimal) as f64;
+    (amount - reduction).floor() as u32
+}
+
 /// Initial hits for observer structures; consider using the
 /// [`StructureType::initial_hits`] function.
 pub const OBSERVER_HITS: u32 = 500;
@@ -822,3 +1084,134 @@ pub const STRONGHOLD_DECAY_TICKS: u32 = 75_000;
 // COLORS_ALL implemented via Sequence trait in `small_enums.rs`
 // INTERSHARD_RESOURCES defined in `types.rs`
 // COMMODITIES defined in `recipes.rs`
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn upgrade_rate_capped_at_rcl8() {
+        // lower levels aren't capped
+        for level in 1..8 {
+            assert_eq!(upgrade_energy_per_tick(1, level), 1);
+            assert_eq!(upgrade_energy_per_tick(15, level), 15);
+            assert_eq!(upgrade_energy_per_tick(40, level), 40);
+        }
+
+        assert_eq!(upgrade_energy_per_tick(10, 8), 10);
+        assert_eq!(upgrade_energy_per_tick(15, 8), 15);
+        assert_eq!(
+            upgrade_energy_per_tick(16, 8),
+            CONTROLLER_MAX_UPGRADE_PER_TICK
+        );
+        assert_eq!(
+            upgrade_energy_per_tick(40, 8),
+            CONTROLLER_MAX_UPGRADE_PER_TICK
+        );
+    }
+
+    #[test]
+    fn upgrade_progress_with_boosts() {
+        let boost = |resource: ResourceType| resource.boost();
+
+        assert_eq!(upgrade_progress_per_tick(10, None, 7), 10);
+        assert_eq!(
+            upgrade_progress_per_tick(10, boost(ResourceType::GhodiumHydride), 7),
+            15
+        );
+        assert_eq!(
+            upgrade_progress_per_tick(5, boost(ResourceType::GhodiumAcid), 7),
+            9
+        );
+        assert_eq!(
+            upgrade_progress_per_tick(3, boost(ResourceType::GhodiumHydride), 7),
+            4
+        );
+        assert_eq!(
+            upgrade_progress_per_tick(40, boost(ResourceType::CatalyzedGhodiumAcid), 7),
+            80
+        );
+        // the boost multiplies the capped energy at RCL8
+        assert_eq!(
+            upgrade_progress_per_tick(40, boost(ResourceType::CatalyzedGhodiumAcid), 8),
+            30
+        );
+        // other boosts on work parts don't help upgrading
+        assert_eq!(
+            upgrade_progress_per_tick(10, boost(ResourceType::UtriumOxide), 7),
+            10
+        );
+    }
+
+    #[test]
+    fn move_cost_by_terrain() {
+        // a balanced body moves unhindered on plains, but not swamps
+        assert_eq!(terrain_move_cost(Terrain::Plain, false, 10, 10), 0);
+        assert_eq!(terrain_move_cost(Terrain::Swamp, false, 10, 10), 80);
+        assert_eq!(terrain_move_cost(Terrain::Plain, true, 10, 10), 0);
+        assert_eq!(terrain_move_cost(Terrain::Swamp, true, 10, 10), 0);
+        // five move parts per other part crosses swamps unhindered
+        assert_eq!(terrain_move_cost(Terrain::Swamp, false, 25, 5), 0);
+        assert_eq!(terrain_move_cost(Terrain::Swamp, false, 24, 5), 2);
+        // roads halve the fatigue of plains
+        assert_eq!(terrain_move_cost(Terrain::Plain, false, 5, 10), 10);
+        assert_eq!(terrain_move_cost(Terrain::Plain, true, 5, 10), 0);
+
+        // without move parts, none of the fatigue is removed
+        assert_eq!(terrain_move_cost(Terrain::Plain, false, 0, 5), 10);
+        assert_eq!(terrain_move_cost(Terrain::Swamp, false, 1, 0), 0);
+        assert_eq!(terrain_move_cost(Terrain::Wall, false, 5, 5), u32::MAX);
+        assert_eq!(terrain_move_cost(Terrain::Wall, true, 5, 5), 0);
+    }
+
+    #[test]
+    fn move_ticks_by_terrain() {
+        // one move part per other part
+        assert_eq!(terrain_move_ticks(Terrain::Plain, false, 10, 10), Some(1));
+        assert_eq!(terrain_move_ticks(Terrain::Plain, true, 10, 10), Some(1));
+        assert_eq!(terrain_move_ticks(Terrain::Swamp, false, 10, 10), Some(5));
+        assert_eq!(terrain_move_ticks(Terrain::Swamp, true, 10, 10), Some(1));
+        // five move parts per other part crosses swamps unhindered
+        assert_eq!(terrain_move_ticks(Terrain::Swamp, false, 25, 5), Some(1));
+        assert_eq!(terrain_move_ticks(Terrain::Swamp, false, 24, 5), Some(2));
+
+        // only move parts, or empty carry parts
+        assert_eq!(terrain_move_ticks(Terrain::Swamp, false, 1, 0), Some(1));
+
+        // no moving without move parts, or into walls without roads
+        assert_eq!(terrain_move_ticks(Terrain::Plain, false, 0, 5), None);
+        assert_eq!(terrain_move_ticks(Terrain::Plain, false, 0, 0), None);
+        assert_eq!(terrain_move_ticks(Terrain::Wall, false, 5, 5), None);
+        assert_eq!(terrain_move_ticks(Terrain::Wall, true, 5, 5), Some(1));
+    }
+
+    #[test]
+    fn spawn_and_renew_times() {
+        assert_eq!(spawn_duration(&[]), 0);
+        assert_eq!(spawn_duration(&[Part::Work, Part::Carry, Part::Move]), 9);
+        assert_eq!(spawn_duration(&[Part::Move; 50]), 150);
+
+        assert_eq!(renew_ticks_per_call(0), 0);
+        for body_size in 1..=50 {
+            assert_eq!(
+                renew_ticks_per_call(body_size),
+                (600.0 / body_size as f64).floor() as u32
+            );
+        }
+        assert_eq!(renew_ticks_per_call(1), 600);
+        assert_eq!(renew_ticks_per_call(7), 85);
+        assert_eq!(renew_ticks_per_call(50), 12);
+    }
+
+    #[test]
+    fn prespawn_boundary() {
+        let spawn_ticks = spawn_duration(&[Part::Work, Part::Carry, Part::Move]);
+        assert!(!should_prespawn(spawn_ticks + 21, spawn_ticks, 20));
+        assert!(should_prespawn(spawn_ticks + 20, spawn_ticks, 20));
+        assert!(should_prespawn(spawn_ticks + 19, spawn_ticks, 20));
+
+        assert!(!should_prespawn(1, 0, 0));
+        assert!(should_prespawn(0, 0, 0));
+        assert!(should_prespawn(CREEP_LIFE_TIME, u32::MAX, 1));
+    }
+}
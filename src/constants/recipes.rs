@@ -97,6 +97,39 @@ impl ResourceType {
         Some(components)
     }
 
+    /// Get the two resources this compound breaks down into when run through
+    /// a reverse reaction with [`StructureLab::reverse_reaction`].
+    ///
+    /// This is the same table as [`ResourceType::reaction_components`], and
+    /// returns `None` for base minerals and other resources which aren't
+    /// produced by a reaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use screeps::ResourceType;
+    ///
+    /// assert_eq!(
+    ///     ResourceType::GhodiumAcid.decompose(),
+    ///     Some((ResourceType::GhodiumHydride, ResourceType::Hydroxide))
+    /// );
+    /// assert_eq!(
+    ///     ResourceType::Ghodium.decompose().map(|(a, _)| a),
+    ///     Some(ResourceType::UtriumLemergite)
+    /// );
+    /// assert_eq!(ResourceType::Zynthium.decompose(), None);
+    /// ```
+    ///
+    /// [`StructureLab::reverse_reaction`]:
+    /// crate::objects::StructureLab::reverse_reaction
+    #[inline]
+    pub const fn decompose(self) -> Option<(ResourceType, ResourceType)> {
+        match self.reaction_components() {
+            Some([first, second]) => Some((first, second)),
+            None => None,
+        }
+    }
+
     /// Translates the `REACTION_TIME` constant.
     #[inline]
     pub const fn reaction_time(self) -> Option<u32> {
@@ -741,3 +774,45 @@ impl ResourceType {
         Some(recipe)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn compounds_decompose_to_their_inputs() {
+        assert_eq!(
+            ResourceType::CatalyzedKeaniumAlkalide.decompose(),
+            Some((ResourceType::KeaniumAlkalide, ResourceType::Catalyst))
+        );
+        assert_eq!(
+            ResourceType::Hydroxide.decompose(),
+            Some((ResourceType::Oxygen, ResourceType::Hydrogen))
+        );
+        for base in [
+            ResourceType::Hydrogen,
+            ResourceType::Catalyst,
+            ResourceType::Energy,
+        ] {
+            assert_eq!(base.decompose(), None);
+        }
+    }
+
+    #[test]
+    fn reaction_time_base_and_catalyzed() {
+        assert_eq!(ResourceType::UtriumHydride.reaction_time(), Some(10));
+        assert_eq!(ResourceType::CatalyzedUtriumAcid.reaction_time(), Some(60));
+        assert_eq!(ResourceType::Utrium.reaction_time(), None);
+    }
+
+    #[test]
+    fn reaction_time_matches_reaction_components() {
+        for resource in enum_iterator::all::<ResourceType>() {
+            assert_eq!(
+                resource.reaction_components().is_some(),
+                resource.reaction_time().is_some(),
+                "mismatched reaction data for {resource:?}"
+            );
+        }
+    }
+}
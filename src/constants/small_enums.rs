@@ -194,6 +194,57 @@ impl Direction {
         self.multi_rot(-1)
     }
 
+    /// Returns the change in (x, y) when moving one step in this direction.
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::Direction::*;
+    ///
+    /// assert_eq!(Top.offset(), (0, -1));
+    /// assert_eq!(BottomLeft.offset(), (-1, 1));
+    /// ```
+    #[inline]
+    pub const fn offset(self) -> (i8, i8) {
+        match self {
+            Direction::Top => (0, -1),
+            Direction::TopRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::BottomRight => (1, 1),
+            Direction::Bottom => (0, 1),
+            Direction::BottomLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::TopLeft => (-1, -1),
+        }
+    }
+
+    /// Returns the direction of a one step change in (x, y), or `None` if the
+    /// offset isn't one of the 8 unit steps.
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::Direction::{self, *};
+    ///
+    /// assert_eq!(Direction::from_offset(1, -1), Some(TopRight));
+    /// assert_eq!(Direction::from_offset(0, 0), None);
+    /// assert_eq!(Direction::from_offset(2, 0), None);
+    /// ```
+    #[inline]
+    pub const fn from_offset(dx: i8, dy: i8) -> Option<Direction> {
+        match (dx, dy) {
+            (0, -1) => Some(Direction::Top),
+            (1, -1) => Some(Direction::TopRight),
+            (1, 0) => Some(Direction::Right),
+            (1, 1) => Some(Direction::BottomRight),
+            (0, 1) => Some(Direction::Bottom),
+            (-1, 1) => Some(Direction::BottomLeft),
+            (-1, 0) => Some(Direction::Left),
+            (-1, -1) => Some(Direction::TopLeft),
+            _ => None,
+        }
+    }
+
     /// Returns an iterator over all 8 direction constants, in clockwise order.
     ///
     /// Example usage:
@@ -259,16 +310,8 @@ impl From<Direction> for (i32, i32) {
     /// Returns the change in (x, y) when moving in each direction.
     #[inline]
     fn from(direction: Direction) -> (i32, i32) {
-        match direction {
-            Direction::Top => (0, -1),
-            Direction::TopRight => (1, -1),
-            Direction::Right => (1, 0),
-            Direction::BottomRight => (1, 1),
-            Direction::Bottom => (0, 1),
-            Direction::BottomLeft => (-1, 1),
-            Direction::Left => (-1, 0),
-            Direction::TopLeft => (-1, -1),
-        }
+        let (dx, dy) = direction.offset();
+        (dx as i32, dy as i32)
     }
 }
 
@@ -387,6 +430,9 @@ impl From<ExitDirection> for Exit {
 }
 
 /// Translates `COLOR_*` and `COLORS_ALL` constants.
+///
+/// `COLORS_ALL` is available via [`enum_iterator::all`], and numeric values
+/// convert with `as u8` and [`FromPrimitive::from_u8`].
 #[wasm_bindgen]
 #[derive(
     Debug,
@@ -560,8 +606,90 @@ impl Density {
 
 /// Translates `ORDER_*` constants.
 #[wasm_bindgen]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Sequence)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence)]
 pub enum OrderType {
     Sell = "sell",
     Buy = "buy",
 }
+
+named_enum_serialize_deserialize!(OrderType);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn direction_offset_roundtrip() {
+        for direction in Direction::iter() {
+            let (dx, dy) = direction.offset();
+            assert_eq!(Direction::from_offset(dx, dy), Some(*direction));
+            assert_eq!((-*direction).offset(), (-dx, -dy));
+            assert_eq!(<(i32, i32)>::from(*direction), (dx as i32, dy as i32));
+        }
+
+        for dx in -2i8..=2 {
+            for dy in -2i8..=2 {
+                let is_step = (dx, dy) != (0, 0) && dx.abs() <= 1 && dy.abs() <= 1;
+                assert_eq!(Direction::from_offset(dx, dy).is_some(), is_step);
+            }
+        }
+    }
+
+    #[test]
+    fn error_code_from_return_code() {
+        // the return codes `Creep::move_direction` can produce
+        assert_eq!(ErrorCode::result_from_i8(0), Ok(()));
+        assert_eq!(ErrorCode::result_from_i8(-1), Err(ErrorCode::NotOwner));
+        assert_eq!(ErrorCode::result_from_i8(-4), Err(ErrorCode::Busy));
+        assert_eq!(ErrorCode::result_from_i8(-10), Err(ErrorCode::InvalidArgs));
+        assert_eq!(ErrorCode::result_from_i8(-11), Err(ErrorCode::Tired));
+        assert_eq!(ErrorCode::result_from_i8(-12), Err(ErrorCode::NoBodypart));
+
+        assert_eq!(ErrorCode::try_result_from_i8(-13), None);
+        assert_eq!(ErrorCode::try_result_from_i8(1), None);
+    }
+
+    #[test]
+    fn error_code_return_code_roundtrip() {
+        for code in (-15..=-1).filter(|code| *code != -13) {
+            let err = ErrorCode::result_from_i8(code).unwrap_err();
+            assert_eq!(err as i8, code);
+            assert_eq!(ErrorCode::from_i8(code), Some(err));
+        }
+    }
+
+    #[test]
+    fn color_values_and_roundtrip() {
+        let colors: Vec<Color> = enum_iterator::all::<Color>().collect();
+        assert_eq!(colors.len(), 10);
+        assert_eq!(colors.first(), Some(&Color::Red));
+        assert_eq!(Color::Red as u8, 1);
+        assert_eq!(Color::White as u8, 10);
+
+        for (i, color) in colors.into_iter().enumerate() {
+            // COLOR_* constants count up from 1 in `COLORS_ALL` order
+            assert_eq!(color as u8, i as u8 + 1);
+            assert_eq!(Color::from_u8(color as u8), Some(color));
+        }
+
+        assert_eq!(Color::from_u8(0), None);
+        assert_eq!(Color::from_u8(11), None);
+    }
+
+    #[test]
+    fn part_vec_serde_json_roundtrip() {
+        let body = vec![Part::Work, Part::Carry, Part::Move, Part::RangedAttack];
+        let serialized = serde_json::to_string(&body).unwrap();
+        assert_eq!(serialized, r#"["work","carry","move","ranged_attack"]"#);
+        let parsed: Vec<Part> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(parsed, body);
+
+        let all: Vec<Part> = enum_iterator::all::<Part>()
+            .filter(|part| *part != Part::__Invalid)
+            .collect();
+        let serialized = serde_json::to_string(&all).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Part>>(&serialized).unwrap(), all);
+
+        assert!(serde_json::from_str::<Part>(r#""wing""#).is_err());
+    }
+}
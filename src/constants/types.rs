@@ -1,5 +1,5 @@
 //! `*Type` constants.
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, cell::RefCell, fmt};
 
 use enum_iterator::Sequence;
 use num_derive::FromPrimitive;
@@ -11,7 +11,7 @@ use serde::{
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use wasm_bindgen::prelude::*;
 
-use super::{macros::named_enum_serialize_deserialize, InvalidConstantString};
+use super::{macros::named_enum_serialize_deserialize, InvalidConstantString, Part};
 use crate::{JsCollectionFromValue, JsCollectionIntoValue};
 
 /// Translates `STRUCTURE_*` constants.
@@ -354,6 +354,95 @@ pub enum ResourceType {
 
 named_enum_serialize_deserialize!(ResourceType);
 
+thread_local! {
+    // JS string values for each resource type, indexed by discriminant and
+    // populated lazily as each type is first converted
+    static RESOURCE_TYPE_JS_VALUES: RefCell<Vec<Option<JsValue>>> = const { RefCell::new(Vec::new()) };
+}
+
+impl ResourceType {
+    /// Converts this resource type into a [`JsValue`] string.
+    ///
+    /// The first conversion of each resource type creates the JS string, and
+    /// later conversions reuse a reference to the same value, avoiding
+    /// re-encoding the string each time a resource type is passed into JS.
+    pub fn to_js_value(self) -> JsValue {
+        RESOURCE_TYPE_JS_VALUES
+            .with(|cache| cached_slot(&mut cache.borrow_mut(), self as usize, || self.into()))
+    }
+}
+
+/// Gets the value in the cache slot at `idx`, first filling it with `make` if
+/// it's empty.
+fn cached_slot<T: Clone>(cache: &mut Vec<Option<T>>, idx: usize, make: impl FnOnce() -> T) -> T {
+    if cache.len() <= idx {
+        cache.resize(idx + 1, None);
+    }
+    cache[idx].get_or_insert_with(make).clone()
+}
+
+impl ResourceType {
+    /// Whether this resource can be bought and sold on the market.
+    ///
+    /// All resources are tradeable except those added by seasonal servers,
+    /// which are meant to be gathered and scored rather than bought.
+    #[inline]
+    pub const fn is_tradeable(self) -> bool {
+        #[cfg(feature = "seasonal-season-1")]
+        if matches!(self, ResourceType::Score) {
+            return false;
+        }
+
+        #[cfg(feature = "seasonal-season-2")]
+        if matches!(
+            self,
+            ResourceType::SymbolAleph
+                | ResourceType::SymbolBeth
+                | ResourceType::SymbolGimmel
+                | ResourceType::SymbolDaleth
+                | ResourceType::SymbolHe
+                | ResourceType::SymbolWaw
+                | ResourceType::SymbolZayin
+                | ResourceType::SymbolHeth
+                | ResourceType::SymbolTeth
+                | ResourceType::SymbolYodh
+                | ResourceType::SymbolKaph
+                | ResourceType::SymbolLamedh
+                | ResourceType::SymbolMem
+                | ResourceType::SymbolNun
+                | ResourceType::SymbolSamekh
+                | ResourceType::SymbolAyin
+                | ResourceType::SymbolPe
+                | ResourceType::SymbolTsade
+                | ResourceType::SymbolQoph
+                | ResourceType::SymbolRes
+                | ResourceType::SymbolSin
+                | ResourceType::SymbolTaw
+        ) {
+            return false;
+        }
+
+        #[cfg(feature = "seasonal-season-5")]
+        if matches!(self, ResourceType::Thorium) {
+            return false;
+        }
+
+        true
+    }
+}
+
+impl JsCollectionIntoValue for ResourceType {
+    fn into_value(self) -> JsValue {
+        self.to_js_value()
+    }
+}
+
+impl JsCollectionFromValue for ResourceType {
+    fn from_value(v: JsValue) -> ResourceType {
+        ResourceType::from_js_value(&v).expect("valid resource type string")
+    }
+}
+
 impl ResourceType {
     /// Translates the `BOOSTS` constant.
     #[inline]
@@ -653,6 +742,105 @@ pub enum Boost {
     Tough(f32),
 }
 
+impl Boost {
+    /// The kind of effect this boost has, without its multiplier.
+    #[inline]
+    pub const fn boost_type(self) -> BoostType {
+        match self {
+            Boost::Harvest(_) => BoostType::Harvest,
+            Boost::BuildAndRepair(_) => BoostType::BuildAndRepair,
+            Boost::Dismantle(_) => BoostType::Dismantle,
+            Boost::UpgradeController(_) => BoostType::UpgradeController,
+            Boost::Attack(_) => BoostType::Attack,
+            Boost::RangedAttack(_) => BoostType::RangedAttack,
+            Boost::Heal(_) => BoostType::Heal,
+            Boost::Carry(_) => BoostType::Carry,
+            Boost::Move(_) => BoostType::Move,
+            Boost::Tough(_) => BoostType::Tough,
+        }
+    }
+}
+
+/// The kinds of effect a [`Boost`] can have on a creep's body parts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence)]
+pub enum BoostType {
+    Harvest,
+    BuildAndRepair,
+    Dismantle,
+    UpgradeController,
+    Attack,
+    RangedAttack,
+    Heal,
+    Carry,
+    Move,
+    Tough,
+}
+
+impl BoostType {
+    /// The body part boosted by this effect.
+    #[inline]
+    pub const fn part(self) -> Part {
+        match self {
+            BoostType::Harvest
+            | BoostType::BuildAndRepair
+            | BoostType::Dismantle
+            | BoostType::UpgradeController => Part::Work,
+            BoostType::Attack => Part::Attack,
+            BoostType::RangedAttack => Part::RangedAttack,
+            BoostType::Heal => Part::Heal,
+            BoostType::Carry => Part::Carry,
+            BoostType::Move => Part::Move,
+            BoostType::Tough => Part::Tough,
+        }
+    }
+}
+
+/// The resource giving a boost effect at the given tier, from 1 for the
+/// weakest compound to 3 for the catalyzed compound; the reverse of
+/// [`ResourceType::boost`].
+///
+/// Returns `None` for tiers other than 1-3.
+///
+/// Example usage:
+///
+/// ```
+/// use screeps::{boost_for, BoostType, ResourceType};
+///
+/// assert_eq!(
+///     boost_for(BoostType::Attack, 3),
+///     Some(ResourceType::CatalyzedUtriumAcid)
+/// );
+/// assert_eq!(
+///     boost_for(BoostType::Move, 1),
+///     Some(ResourceType::ZynthiumOxide)
+/// );
+/// assert_eq!(boost_for(BoostType::Move, 4), None);
+/// ```
+#[inline]
+pub const fn boost_for(boost_type: BoostType, tier: u8) -> Option<ResourceType> {
+    use ResourceType::*;
+    let tiers = match boost_type {
+        BoostType::Harvest => [UtriumOxide, UtriumAlkalide, CatalyzedUtriumAlkalide],
+        BoostType::BuildAndRepair => [LemergiumHydride, LemergiumAcid, CatalyzedLemergiumAcid],
+        BoostType::Dismantle => [ZynthiumHydride, ZynthiumAcid, CatalyzedZynthiumAcid],
+        BoostType::UpgradeController => [GhodiumHydride, GhodiumAcid, CatalyzedGhodiumAcid],
+        BoostType::Attack => [UtriumHydride, UtriumAcid, CatalyzedUtriumAcid],
+        BoostType::RangedAttack => [KeaniumOxide, KeaniumAlkalide, CatalyzedKeaniumAlkalide],
+        BoostType::Heal => [
+            LemergiumOxide,
+            LemergiumAlkalide,
+            CatalyzedLemergiumAlkalide,
+        ],
+        BoostType::Carry => [KeaniumHydride, KeaniumAcid, CatalyzedKeaniumAcid],
+        BoostType::Move => [ZynthiumOxide, ZynthiumAlkalide, CatalyzedZynthiumAlkalide],
+        BoostType::Tough => [GhodiumOxide, GhodiumAlkalide, CatalyzedGhodiumAlkalide],
+    };
+    match tier {
+        1..=3 => Some(tiers[tier as usize - 1]),
+        _ => None,
+    }
+}
+
 /// Translates all resource types that can be used on the market.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Sequence)]
 #[serde(untagged)]
@@ -661,6 +849,34 @@ pub enum MarketResourceType {
     IntershardResource(IntershardResourceType),
 }
 
+impl MarketResourceType {
+    /// Whether this is an [`IntershardResourceType`], which is traded
+    /// account-wide rather than from a room's terminal.
+    #[inline]
+    pub const fn is_intershard(self) -> bool {
+        matches!(self, MarketResourceType::IntershardResource(_))
+    }
+
+    /// Get the [`ResourceType`] this represents, if it's an in-game resource.
+    #[inline]
+    pub const fn as_resource(self) -> Option<ResourceType> {
+        match self {
+            MarketResourceType::Resource(ty) => Some(ty),
+            MarketResourceType::IntershardResource(_) => None,
+        }
+    }
+
+    /// Get the [`IntershardResourceType`] this represents, if it's an
+    /// intershard resource.
+    #[inline]
+    pub const fn as_intershard(self) -> Option<IntershardResourceType> {
+        match self {
+            MarketResourceType::Resource(_) => None,
+            MarketResourceType::IntershardResource(ty) => Some(ty),
+        }
+    }
+}
+
 impl wasm_bindgen::convert::FromWasmAbi for MarketResourceType {
     type Abi = <wasm_bindgen::JsValue as wasm_bindgen::convert::FromWasmAbi>::Abi;
 
@@ -831,6 +1047,60 @@ impl wasm_bindgen::describe::WasmDescribe for EffectType {
 mod test {
     use super::*;
 
+    #[test]
+    fn cached_slot_reuses_value() {
+        let mut cache: Vec<Option<String>> = Vec::new();
+        let mut made = 0;
+        let mut make = |idx: usize| {
+            made += 1;
+            format!("value {idx}")
+        };
+
+        let idx = 3;
+        assert_eq!(cached_slot(&mut cache, idx, || make(idx)), "value 3");
+        assert_eq!(cached_slot(&mut cache, idx, || make(idx)), "value 3");
+        assert_eq!(cache[idx].as_deref(), Some("value 3"));
+        // other slots are left empty until they're first used
+        assert!(cache[..idx].iter().all(Option::is_none));
+
+        assert_eq!(cached_slot(&mut cache, 0, || make(0)), "value 0");
+        assert_eq!(cached_slot(&mut cache, idx, || make(idx)), "value 3");
+        assert_eq!(made, 2);
+    }
+
+    #[test]
+    fn boost_for_reverses_boost() {
+        for boost_type in enum_iterator::all::<BoostType>() {
+            for tier in 1..=3 {
+                let resource = boost_for(boost_type, tier).unwrap();
+                let boost = resource.boost().unwrap();
+                assert_eq!(boost.boost_type(), boost_type, "{resource}");
+            }
+            assert_eq!(boost_for(boost_type, 0), None);
+            assert_eq!(boost_for(boost_type, 4), None);
+        }
+
+        // every boosting resource is listed under exactly one type and tier
+        let listed: std::collections::HashSet<ResourceType> = enum_iterator::all::<BoostType>()
+            .flat_map(|boost_type| (1..=3).filter_map(move |tier| boost_for(boost_type, tier)))
+            .collect();
+        let boosting = enum_iterator::all::<ResourceType>()
+            .filter(|resource| resource.boost().is_some())
+            .count();
+        assert_eq!(listed.len(), 30);
+        assert_eq!(boosting, 30);
+
+        assert_eq!(
+            boost_for(BoostType::Harvest, 2),
+            Some(ResourceType::UtriumAlkalide)
+        );
+        assert_eq!(
+            boost_for(BoostType::Tough, 3),
+            Some(ResourceType::CatalyzedGhodiumAlkalide)
+        );
+        assert_eq!(BoostType::UpgradeController.part(), Part::Work);
+    }
+
     #[test]
     fn resources_rust_to_serde_json_from_serde_json_roundtrip() {
         for resource in enum_iterator::all::<ResourceType>() {
@@ -989,4 +1259,32 @@ mod test {
             .collect();
         assert_eq!(resources, resources_reparsed_native);
     }
+
+    #[test]
+    fn market_resource_type_partition() {
+        let energy = MarketResourceType::Resource(ResourceType::Energy);
+        assert!(!energy.is_intershard());
+        assert_eq!(energy.as_resource(), Some(ResourceType::Energy));
+        assert_eq!(energy.as_intershard(), None);
+
+        let pixel = MarketResourceType::IntershardResource(IntershardResourceType::Pixel);
+        assert!(pixel.is_intershard());
+        assert_eq!(pixel.as_resource(), None);
+        assert_eq!(pixel.as_intershard(), Some(IntershardResourceType::Pixel));
+    }
+
+    #[test]
+    fn tradeable_resources() {
+        assert!(ResourceType::Energy.is_tradeable());
+        assert!(ResourceType::Power.is_tradeable());
+        assert!(ResourceType::CatalyzedGhodiumAlkalide.is_tradeable());
+        assert!(ResourceType::Battery.is_tradeable());
+
+        #[cfg(feature = "seasonal-season-1")]
+        assert!(!ResourceType::Score.is_tradeable());
+        #[cfg(feature = "seasonal-season-2")]
+        assert!(!ResourceType::SymbolAleph.is_tradeable());
+        #[cfg(feature = "seasonal-season-5")]
+        assert!(!ResourceType::Thorium.is_tradeable());
+    }
 }
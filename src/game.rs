@@ -10,7 +10,7 @@
 //! the behavior of stale game objects is undefined.
 //!
 //! [Screeps documentation](http://docs.screeps.com/api/#Game)
-use js_sys::{JsString, Object};
+use js_sys::{JsString, Object, Reflect};
 use wasm_bindgen::prelude::*;
 
 use crate::{
@@ -28,6 +28,7 @@ use crate::{
 pub mod cpu;
 pub mod gcl;
 pub mod gpl;
+pub mod lifecycle;
 pub mod map;
 pub mod market;
 pub mod shard;
@@ -108,6 +109,27 @@ pub fn creeps_jsstring() -> JsHashMap<JsString, Creep> {
     Game::creeps().into()
 }
 
+/// Get your creeps whose names start with the given prefix, such as a role
+/// name.
+///
+/// Names are checked in JavaScript, so creeps which don't match are never
+/// converted; this is cheaper than filtering the result of [`creeps`] when
+/// only a few creeps match.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.creeps)
+pub fn creeps_with_prefix(prefix: &str) -> Vec<Creep> {
+    let creeps = Game::creeps();
+    Object::keys(&creeps)
+        .iter()
+        .filter(|name| name.unchecked_ref::<JsString>().starts_with(prefix, 0))
+        .map(|name| {
+            Reflect::get(&creeps, &name)
+                .expect("expected creep to exist for its name")
+                .unchecked_into()
+        })
+        .collect()
+}
+
 /// Get a [`JsHashMap<String, Flag>`] with all of your flags, which has flag
 /// names as keys.
 ///
@@ -148,6 +170,15 @@ pub fn resources() -> JsHashMap<IntershardResourceType, u32> {
     Game::resources().into()
 }
 
+/// Get the number of pixels in your account, a shortcut for the
+/// [`IntershardResourceType::Pixel`] entry in [`resources`].
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.resources)
+#[cfg(feature = "mmo")]
+pub fn pixels() -> u32 {
+    resources().get(IntershardResourceType::Pixel).unwrap_or(0)
+}
+
 /// Get a [`JsHashMap<RoomName, Room>`] with the rooms visible for the current
 /// tick.
 ///
@@ -156,6 +187,16 @@ pub fn rooms() -> JsHashMap<RoomName, Room> {
     Game::rooms().into()
 }
 
+/// Get the names of the rooms visible for the current tick.
+///
+/// This only reads the keys of [`rooms`], without converting any [`Room`]
+/// objects.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.rooms)
+pub fn visible_rooms() -> Vec<RoomName> {
+    rooms().keys().collect()
+}
+
 /// Get a [`JsHashMap<String, StructureSpawn>`] with all of your spawns, which
 /// has spawn names as keys.
 ///
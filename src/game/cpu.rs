@@ -1,6 +1,8 @@
 //! Information about, and functions to manage, your code's resource utilization
 //!
 //! [Screeps documentation](http://docs.screeps.com/api/#Game.cpu)
+use std::cell::Cell;
+
 use wasm_bindgen::prelude::*;
 
 #[cfg(feature = "mmo")]
@@ -57,6 +59,8 @@ extern "C" {
 }
 
 /// Your assigned CPU for the current shard.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.cpu)
 pub fn limit() -> u32 {
     Cpu::limit()
 }
@@ -64,14 +68,22 @@ pub fn limit() -> u32 {
 /// The amount of CPU available for execution in a given tick.
 ///
 /// Consists of your per-tick CPU [`limit`] plus your accrued [`bucket`], up to
-/// a maximum of 500 ([`CPU_TICK_LIMIT_MAX`]); [`f64::INFINITY`] on sim.
+/// a maximum of 500 ([`CPU_TICK_LIMIT_MAX`]); [`f64::INFINITY`] on sim, which
+/// is why this is an `f64` rather than an integer like [`limit`].
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.cpu)
 ///
 /// [`CPU_TICK_LIMIT_MAX`]: crate::constants::extra::CPU_TICK_LIMIT_MAX
 pub fn tick_limit() -> f64 {
     Cpu::tick_limit()
 }
 
-/// The amount of CPU that has accumulated in your bucket.
+/// The amount of CPU that has accumulated in your bucket, up to a maximum of
+/// [`CPU_BUCKET_MAX`].
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.cpu)
+///
+/// [`CPU_BUCKET_MAX`]: crate::constants::extra::CPU_BUCKET_MAX
 pub fn bucket() -> i32 {
     Cpu::bucket()
 }
@@ -112,6 +124,127 @@ pub fn get_used() -> f64 {
     Cpu::get_used()
 }
 
+/// Run a function, returning its result along with the CPU time used while it
+/// ran, as measured by [`get_used`].
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::game;
+///
+/// let (creep_count, cpu_used) = game::cpu::measure(|| game::creeps().keys().count());
+/// println!("counted {creep_count} creeps using {cpu_used} CPU");
+/// ```
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, f64) {
+    measure_with(get_used, f)
+}
+
+fn measure_with<T>(mut clock: impl FnMut() -> f64, f: impl FnOnce() -> T) -> (T, f64) {
+    let start = clock();
+    let result = f();
+    (result, clock() - start)
+}
+
+thread_local! {
+    static TICK_MARK: Cell<Option<(u32, f64)>> = const { Cell::new(None) };
+}
+
+/// Record the CPU used so far this tick as the baseline for
+/// [`tick_cpu_used`].
+///
+/// Call this at the start of your game loop to exclude the CPU spent before
+/// your loop runs. This matters most on the first tick after a global reset,
+/// where [`get_used`] includes the time spent compiling and instantiating your
+/// code - often far more than a normal tick's usage.
+pub fn start_tick_mark() {
+    mark_at(super::time(), get_used());
+}
+
+/// Get the CPU used since [`start_tick_mark`] was called this tick.
+///
+/// If the mark wasn't set this tick, this is the same as [`get_used`], and
+/// so includes any pre-loop overhead, such as compile time on the first tick
+/// after a global reset.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::game;
+///
+/// game::cpu::start_tick_mark();
+/// // ... run the bot's loop ...
+/// println!("loop used {} CPU", game::cpu::tick_cpu_used());
+/// ```
+pub fn tick_cpu_used() -> f64 {
+    used_since_mark_at(super::time(), get_used())
+}
+
+fn mark_at(tick: u32, used: f64) {
+    TICK_MARK.with(|mark| mark.set(Some((tick, used))));
+}
+
+fn used_since_mark_at(tick: u32, used: f64) -> f64 {
+    match TICK_MARK.with(Cell::get) {
+        Some((mark_tick, mark_used)) if mark_tick == tick => used - mark_used,
+        _ => used,
+    }
+}
+
+/// Gates expensive work on the health of the CPU [`bucket`].
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::game::cpu::Throttle;
+///
+/// let throttle = Throttle::new(5000);
+///
+/// if throttle.should_run() {
+///     // plan new construction sites
+/// }
+/// if throttle.should_run_every(100) {
+///     // rescan remote rooms
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Throttle {
+    min_bucket: i32,
+}
+
+impl Throttle {
+    /// Create a `Throttle` which allows work to run while the bucket has at
+    /// least `min_bucket` CPU in it.
+    #[inline]
+    pub const fn new(min_bucket: i32) -> Self {
+        Throttle { min_bucket }
+    }
+
+    /// The minimum bucket level at which work is allowed to run.
+    #[inline]
+    pub const fn min_bucket(self) -> i32 {
+        self.min_bucket
+    }
+
+    /// Whether the bucket is healthy enough to run expensive work this tick.
+    pub fn should_run(self) -> bool {
+        self.should_run_at(bucket(), super::time(), 1)
+    }
+
+    /// Whether the bucket is healthy enough to run expensive work this tick,
+    /// and the game time is a multiple of `interval` ticks; an `interval` of 0
+    /// or 1 checks only the bucket.
+    ///
+    /// Note that work gated this way is skipped entirely, not delayed, on an
+    /// interval tick where the bucket is too low.
+    pub fn should_run_every(self, interval: u32) -> bool {
+        self.should_run_at(bucket(), super::time(), interval)
+    }
+
+    fn should_run_at(self, bucket: i32, tick: u32, interval: u32) -> bool {
+        bucket >= self.min_bucket && (interval <= 1 || tick.is_multiple_of(interval))
+    }
+}
+
 /// Stop execution of your script immediately and requests the destruction of
 /// your code's environment, which will start fresh on the following tick.
 ///
@@ -212,3 +345,60 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn externally_allocated_size(this: &HeapStatistics) -> u32;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn measure_returns_result_and_delta() {
+        let mut readings = [1.25, 3.5].into_iter();
+        let mut calls = 0;
+        let (result, used) = measure_with(
+            || readings.next().unwrap(),
+            || {
+                calls += 1;
+                "result"
+            },
+        );
+
+        assert_eq!(result, "result");
+        assert_eq!(calls, 1);
+        assert_eq!(used, 2.25);
+        assert!(used >= 0.);
+    }
+
+    #[test]
+    fn tick_mark_applies_only_to_its_tick() {
+        // no mark yet
+        assert_eq!(used_since_mark_at(100, 4.5), 4.5);
+
+        // first tick after a reset, with compile time before the loop
+        mark_at(100, 30.0);
+        assert_eq!(used_since_mark_at(100, 30.0), 0.0);
+        assert_eq!(used_since_mark_at(100, 42.5), 12.5);
+
+        // the mark from the previous tick is ignored
+        assert_eq!(used_since_mark_at(101, 3.0), 3.0);
+        mark_at(101, 0.5);
+        assert_eq!(used_since_mark_at(101, 3.0), 2.5);
+    }
+
+    #[test]
+    fn throttle_gates_on_bucket_and_interval() {
+        let throttle = Throttle::new(5000);
+
+        // low bucket never runs
+        assert!(!throttle.should_run_at(4999, 100, 1));
+        assert!(!throttle.should_run_at(0, 100, 10));
+        assert!(!throttle.should_run_at(-1, 100, 0));
+
+        // healthy bucket runs every tick without an interval
+        assert!(throttle.should_run_at(5000, 101, 1));
+        assert!(throttle.should_run_at(10_000, 103, 0));
+
+        // and on interval ticks otherwise
+        assert!(throttle.should_run_at(10_000, 100, 10));
+        assert!(!throttle.should_run_at(10_000, 105, 10));
+    }
+}
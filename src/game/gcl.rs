@@ -33,3 +33,47 @@ pub fn progress() -> f64 {
 pub fn progress_total() -> f64 {
     Gcl::progress_total()
 }
+
+/// Progress still needed to reach the next Global Control Level.
+pub fn progress_remaining() -> f64 {
+    remaining_progress(progress(), progress_total())
+}
+
+/// Estimate the number of ticks until reaching the next Global Control Level,
+/// given an average number of control points gained per tick.
+///
+/// Returns `None` if `points_per_tick` isn't a positive number.
+pub fn ticks_to_next_level(points_per_tick: f64) -> Option<u32> {
+    ticks_at_rate(progress_remaining(), points_per_tick)
+}
+
+pub(super) fn remaining_progress(progress: f64, progress_total: f64) -> f64 {
+    (progress_total - progress).max(0.)
+}
+
+pub(super) fn ticks_at_rate(remaining: f64, per_tick: f64) -> Option<u32> {
+    if per_tick > 0. && per_tick.is_finite() {
+        Some((remaining / per_tick).ceil() as u32)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn remaining_progress_and_ticks() {
+        assert_eq!(remaining_progress(1_278_031.5, 5_278_031.5), 4_000_000.);
+        assert_eq!(remaining_progress(0., 1_000_000.), 1_000_000.);
+        assert_eq!(remaining_progress(1_000_010., 1_000_000.), 0.);
+
+        assert_eq!(ticks_at_rate(4_000_000., 15.), Some(266_667));
+        assert_eq!(ticks_at_rate(4_000_000., 40.), Some(100_000));
+        assert_eq!(ticks_at_rate(0., 40.), Some(0));
+        assert_eq!(ticks_at_rate(1000., 0.), None);
+        assert_eq!(ticks_at_rate(1000., -5.), None);
+        assert_eq!(ticks_at_rate(1000., f64::NAN), None);
+    }
+}
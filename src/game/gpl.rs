@@ -33,3 +33,16 @@ pub fn progress() -> f64 {
 pub fn progress_total() -> f64 {
     Gpl::progress_total()
 }
+
+/// Progress still needed to reach the next Global Power Level.
+pub fn progress_remaining() -> f64 {
+    super::gcl::remaining_progress(progress(), progress_total())
+}
+
+/// Estimate the number of ticks until reaching the next Global Power Level,
+/// given an average amount of power processed per tick.
+///
+/// Returns `None` if `power_per_tick` isn't a positive number.
+pub fn ticks_to_next_level(power_per_tick: f64) -> Option<u32> {
+    super::gcl::ticks_at_rate(progress_remaining(), power_per_tick)
+}
@@ -0,0 +1,56 @@
+//! Running setup once per global reset.
+//!
+//! A global reset reloads your code into a fresh environment, discarding all
+//! state held in Rust, so anything built up in memory (lookup tables, parsed
+//! configuration, caches) needs to be rebuilt on the first tick afterwards.
+use std::cell::Cell;
+
+thread_local! {
+    static RAN: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Run `f` if this is the first call to `run_once` since the last global
+/// reset, and do nothing on every later call until the next reset.
+///
+/// Calling this at the start of your game loop runs `f` on the first tick
+/// after each reset. There's a single flag shared by every call, so only the
+/// first closure passed after a reset is run; put all of your setup in one
+/// closure.
+///
+/// The flag is set before `f` runs, so `f` isn't retried on later ticks if it
+/// panics.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::game;
+///
+/// pub fn game_loop() {
+///     game::lifecycle::run_once(|| {
+///         // build lookup tables, parse configuration, ...
+///     });
+///     // ... run the bot's loop ...
+/// }
+/// ```
+pub fn run_once(f: impl FnOnce()) {
+    if !RAN.with(|ran| ran.replace(true)) {
+        f();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn runs_only_on_first_tick() {
+        let mut runs = 0;
+        for _tick in 0..5 {
+            run_once(|| runs += 1);
+        }
+        assert_eq!(runs, 1);
+
+        // a later closure isn't run either
+        run_once(|| panic!("ran again"));
+    }
+}
@@ -1,6 +1,8 @@
 //! Game map related functionality.
 //!
 //! [Screeps documentation](https://docs.screeps.com/api/#Game-map)
+use std::collections::HashMap;
+
 use enum_iterator::Sequence;
 use js_sys::{Array, JsString, Object};
 use num_traits::*;
@@ -9,7 +11,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     constants::{Direction, ErrorCode, ExitDirection},
-    local::RoomName,
+    local::{RoomName, HALF_WORLD_SIZE},
     objects::RoomTerrain,
     prelude::*,
 };
@@ -41,9 +43,12 @@ extern "C" {
     fn get_room_status(room_name: &JsString) -> Result<JsRoomStatusResult, JsValue>;
 }
 
-/// Get an object with information about the exits from a given room, with
-/// [`JsString`] versions of direction integers as keys and [`JsString`]
-/// room names as values.
+/// Get a [`JsHashMap<Direction, RoomName>`] with information about the exits
+/// from a given room, with the [`Direction`] of each exit as keys and the
+/// name of the room it leads to as values.
+///
+/// Directions without an exit (or leading to a room outside the world) are
+/// not present in the map.
 ///
 /// [Screeps documentation](https://docs.screeps.com/api/#Game.map.describeExits)
 pub fn describe_exits(room_name: RoomName) -> JsHashMap<Direction, RoomName> {
@@ -52,6 +57,34 @@ pub fn describe_exits(room_name: RoomName) -> JsHashMap<Direction, RoomName> {
     Map::describe_exits(&room_name).into()
 }
 
+/// Build an adjacency list of the given rooms, calling [`describe_exits`] once
+/// for each, mapping each room to the rooms its exits lead to, in
+/// [`Direction`] order.
+///
+/// The neighboring rooms don't need to be in `rooms` themselves, so a search
+/// over the room graph can grow it by calling this again with newly found
+/// rooms.
+pub fn room_graph(rooms: &[RoomName]) -> HashMap<RoomName, Vec<RoomName>> {
+    build_room_graph(rooms, |room_name| {
+        describe_exits(room_name).entries().collect()
+    })
+}
+
+fn build_room_graph(
+    rooms: &[RoomName],
+    mut exits: impl FnMut(RoomName) -> Vec<(Direction, RoomName)>,
+) -> HashMap<RoomName, Vec<RoomName>> {
+    let mut graph = HashMap::with_capacity(rooms.len());
+    for &room_name in rooms {
+        graph.entry(room_name).or_insert_with(|| {
+            let mut room_exits = exits(room_name);
+            room_exits.sort_unstable_by_key(|(direction, _)| *direction as u8);
+            room_exits.into_iter().map(|(_, room)| room).collect()
+        });
+    }
+    graph
+}
+
 /// Get the distance used for range calculations between two rooms,
 /// optionally setting `continuous` to true to consider the world borders to
 /// wrap around, which is used for terminal calculations.
@@ -67,6 +100,11 @@ pub fn get_room_linear_distance(from_room: RoomName, to_room: RoomName, continuo
 /// Get the [`RoomTerrain`] object for any room, even one you don't have
 /// vision in.
 ///
+/// This replaces the deprecated `Game.map.getTerrainAt`, which isn't bound
+/// here; to check many tiles, get the terrain object for the room once and
+/// reuse it, or copy it out with [`RoomTerrain::get_raw_buffer`] and read it
+/// from Rust without further calls into JavaScript.
+///
 /// [Screeps documentation](https://docs.screeps.com/api/#Game.map.getRoomTerrain)
 pub fn get_room_terrain(room_name: RoomName) -> Option<RoomTerrain> {
     let name = room_name.into();
@@ -81,6 +119,25 @@ pub fn get_world_size() -> u32 {
     Map::get_world_size()
 }
 
+/// Get the lowest and highest room coordinates in the world, as used by
+/// [`RoomName::x_coord`] and [`RoomName::y_coord`], derived from
+/// [`get_world_size`].
+///
+/// The world is square, so the bounds are the same for both axes. For the
+/// official servers' world size of 202, this is `(-101, 100)`, covering
+/// `W100` to `E100` and `N100` to `S100`; private servers may be smaller.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.map.getWorldSize)
+pub fn world_bounds() -> (i32, i32) {
+    bounds_for_world_size(get_world_size())
+}
+
+fn bounds_for_world_size(world_size: u32) -> (i32, i32) {
+    // clamped to the coordinates a `RoomName` can represent
+    let half = (world_size / 2).min(HALF_WORLD_SIZE as u32) as i32;
+    (-half, half - 1)
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen]
@@ -188,7 +245,7 @@ where
         // passed in, now unified as &Fn
         //
 
-        let callback_type_erased: &mut (dyn FnMut(RoomName, RoomName) -> f64) = &mut owned_callback;
+        let callback_type_erased: &mut dyn FnMut(RoomName, RoomName) -> f64 = &mut owned_callback;
 
         // Overwrite lifetime of reference so it can be passed to javascript.
         // It's now pretending to be static data. This should be entirely safe
@@ -197,7 +254,7 @@ where
         // above the current scope but otherwise unknown" is not a valid lifetime.
         //
 
-        let callback_lifetime_erased: &'static mut (dyn FnMut(RoomName, RoomName) -> f64) =
+        let callback_lifetime_erased: &'static mut dyn FnMut(RoomName, RoomName) -> f64 =
             unsafe { std::mem::transmute(callback_type_erased) };
 
         let boxed_callback = Box::new(move |to_room: JsString, from_room: JsString| -> f64 {
@@ -309,6 +366,33 @@ where
     }
 }
 
+/// Get the rooms a route from a given room to a destination room passes
+/// through, starting with the origin room and ending with the destination,
+/// using [`find_route`] with an optional [`FindRouteOptions`] parameter
+/// allowing control over the costs to enter rooms.
+///
+/// Returns `None` if no route could be found.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.map.findRoute)
+pub fn rooms_between<F>(
+    from: RoomName,
+    to: RoomName,
+    options: Option<FindRouteOptions<F>>,
+) -> Option<Vec<RoomName>>
+where
+    F: FnMut(RoomName, RoomName) -> f64,
+{
+    find_route(from, to, options)
+        .ok()
+        .map(|steps| route_rooms(from, &steps))
+}
+
+fn route_rooms(from: RoomName, steps: &[RouteStep]) -> Vec<RoomName> {
+    std::iter::once(from)
+        .chain(steps.iter().map(|step| step.room))
+        .collect()
+}
+
 /// Get the exit direction from a given room leading toward a destination
 /// room, with an optional [`FindRouteOptions`] parameter allowing control
 /// over the costs to enter rooms.
@@ -339,3 +423,74 @@ where
         Err(unsafe { ErrorCode::result_from_i8(result).unwrap_err_unchecked() })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn room(name: &str) -> RoomName {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn route_rooms_start_at_origin() {
+        let steps = [
+            RouteStep {
+                exit: ExitDirection::Right,
+                room: room("W4N5"),
+            },
+            RouteStep {
+                exit: ExitDirection::Top,
+                room: room("W4N6"),
+            },
+        ];
+        assert_eq!(
+            route_rooms(room("W5N5"), &steps),
+            [room("W5N5"), room("W4N5"), room("W4N6")]
+        );
+        assert_eq!(route_rooms(room("W5N5"), &[]), [room("W5N5")]);
+    }
+
+    #[test]
+    fn world_bounds_from_size() {
+        assert_eq!(bounds_for_world_size(202), (-101, 100));
+        assert_eq!(bounds_for_world_size(102), (-51, 50));
+        assert_eq!(bounds_for_world_size(22), (-11, 10));
+        assert_eq!(bounds_for_world_size(256), (-128, 127));
+        assert_eq!(bounds_for_world_size(1000), (-128, 127));
+
+        // W50N50 and E50S50 are the corners of a world of size 102
+        let (min, max) = bounds_for_world_size(102);
+        assert_eq!(room("W50N50").x_coord(), min);
+        assert_eq!(room("W50N50").y_coord(), min);
+        assert_eq!(room("E50S50").x_coord(), max);
+        assert_eq!(room("E50S50").y_coord(), max);
+    }
+
+    #[test]
+    fn room_graph_adjacency() {
+        let mut calls = Vec::new();
+        // W1N1 has no exit to the north, and W2N1 none to the west or south
+        let graph = build_room_graph(&[room("W1N1"), room("W2N1"), room("W1N1")], |room_name| {
+            calls.push(room_name);
+            match room_name.to_string().as_str() {
+                "W1N1" => vec![
+                    (Direction::Left, room("W2N1")),
+                    (Direction::Bottom, room("W1S0")),
+                ],
+                "W2N1" => vec![
+                    (Direction::Right, room("W1N1")),
+                    (Direction::Top, room("W2N2")),
+                ],
+                _ => vec![],
+            }
+        });
+
+        // each room is only described once
+        assert_eq!(calls, vec![room("W1N1"), room("W2N1")]);
+        assert_eq!(graph.len(), 2);
+        // neighbors are in direction order
+        assert_eq!(graph[&room("W1N1")], vec![room("W1S0"), room("W2N1")]);
+        assert_eq!(graph[&room("W2N1")], vec![room("W2N2"), room("W1N1")]);
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! [Screeps documentation](https://docs.screeps.com/api/#Game-market)
 use js_sys::{Array, JsString, Object};
+use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
 use crate::{
@@ -37,7 +38,7 @@ extern "C" {
     fn change_order_price(order_id: &JsString, new_price: f64) -> i8;
 
     #[wasm_bindgen(js_namespace = ["Game"], js_class = "market", static_method_of = Market, js_name = createOrder)]
-    fn create_order(order_parameters: &Object) -> i8;
+    fn create_order(order_parameters: &JsValue) -> i8;
 
     #[wasm_bindgen(js_namespace = ["Game"], js_class = "market", static_method_of = Market, js_name = deal)]
     fn deal(order_id: &JsString, amount: u32, room_name: Option<&JsString>) -> i8;
@@ -98,14 +99,15 @@ pub fn orders_jsstring() -> JsHashMap<JsString, MyOrder> {
     Market::orders().into()
 }
 
-// todo maybe just implement a native version of this instead?
 /// Get the amount of energy required to send a given amount of any resource
 /// from one room to another.  See [`TERMINAL_SEND_COST_SCALE`] for
-/// information about the calculation.
+/// information about the calculation, and [`terminal_send_cost`] for a native
+/// version of it.
 ///
 /// [Screeps documentation](https://docs.screeps.com/api/#Game.market.calcTransactionCost)
 ///
 /// [`TERMINAL_SEND_COST_SCALE`]: crate::constants::TERMINAL_SEND_COST_SCALE
+/// [`terminal_send_cost`]: crate::constants::terminal_send_cost
 pub fn calc_transaction_cost(amount: u32, room_1: &JsString, room_2: &JsString) -> u32 {
     Market::calc_transaction_cost(amount, room_1, room_2)
 }
@@ -129,12 +131,57 @@ pub fn change_order_price(order_id: &JsString, new_price: f64) -> Result<(), Err
     ErrorCode::result_from_i8(Market::change_order_price(order_id, new_price))
 }
 
-// todo type to serialize call options into
-/// Create a new order on the market.
+/// Parameters for a new order on the market, for use with [`create_order`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderParams {
+    #[serde(rename = "type")]
+    order_type: OrderType,
+    resource_type: MarketResourceType,
+    price: f64,
+    total_amount: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    room_name: Option<RoomName>,
+}
+
+impl OrderParams {
+    /// Create parameters for an order to buy or sell `total_amount` of a
+    /// resource at `price` credits per unit.
+    pub fn new(
+        order_type: OrderType,
+        resource_type: MarketResourceType,
+        price: f64,
+        total_amount: u32,
+    ) -> Self {
+        OrderParams {
+            order_type,
+            resource_type,
+            price,
+            total_amount,
+            room_name: None,
+        }
+    }
+
+    /// Sets the room with a terminal the order is placed from, which is
+    /// required for orders of in-game resources and ignored for intershard
+    /// resources.
+    pub fn room_name(mut self, room_name: RoomName) -> Self {
+        self.room_name = Some(room_name);
+        self
+    }
+}
+
+/// Create a new order on the market, paying a fee of [`MARKET_FEE`] times
+/// the total value of the order.
 ///
 /// [Screeps documentation](https://docs.screeps.com/api/#Game.market.createOrder)
-pub fn create_order(order_parameters: &Object) -> Result<(), ErrorCode> {
-    ErrorCode::result_from_i8(Market::create_order(order_parameters))
+///
+/// [`MARKET_FEE`]: crate::constants::MARKET_FEE
+pub fn create_order(order_parameters: &OrderParams) -> Result<(), ErrorCode> {
+    let order_parameters = serde_wasm_bindgen::to_value(order_parameters)
+        .expect("expected to serialize order parameters");
+
+    ErrorCode::result_from_i8(Market::create_order(&order_parameters))
 }
 
 /// Execute a trade on an order on the market. Name of a room with a
@@ -175,6 +222,42 @@ pub fn get_all_orders(filter: Option<&LodashFilter>) -> Vec<Order> {
         .collect()
 }
 
+/// Get the best price currently offered for a resource by orders of the given
+/// type: the lowest price among sell orders, or the highest among buy
+/// orders.
+///
+/// Orders are fetched with a single filtered call to [`get_all_orders`], and
+/// orders with no remaining amount available are ignored. Returns `None` if
+/// there are no such orders.
+pub fn best_price(resource: MarketResourceType, order_type: OrderType) -> Option<f64> {
+    let filter = LodashFilter::new();
+    filter.resource_type(resource);
+    filter.order_type(order_type);
+
+    let orders = get_all_orders(Some(&filter));
+    best_order_price(
+        order_type,
+        orders.iter().map(|order| (order.price(), order.amount())),
+    )
+}
+
+/// Pick the best price from `(price, amount)` pairs of orders of the given
+/// type.
+fn best_order_price(
+    order_type: OrderType,
+    orders: impl Iterator<Item = (f64, u32)>,
+) -> Option<f64> {
+    let prices = orders
+        .filter(|&(price, amount)| amount > 0 && !price.is_nan())
+        .map(|(price, _)| price);
+
+    match order_type {
+        OrderType::Sell => prices.reduce(f64::min),
+        OrderType::Buy => prices.reduce(f64::max),
+        _ => None,
+    }
+}
+
 /// Get information about the price history on the market for the last 14
 /// days.
 ///
@@ -237,14 +320,19 @@ extern "C" {
     pub fn price(this: &Order) -> f64;
 }
 
-// todo docs
 #[wasm_bindgen]
 extern "C" {
+    /// A record of resources sent between terminals, as returned by
+    /// [`incoming_transactions`] and [`outgoing_transactions`].
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Game.market.incomingTransactions)
     #[wasm_bindgen]
     #[derive(Debug)]
     pub type Transaction;
+    /// The unique identifier of this transaction
     #[wasm_bindgen(method, getter = transactionId)]
     pub fn transaction_id(this: &Transaction) -> JsString;
+    /// The game tick that this transaction took place
     #[wasm_bindgen(method, getter)]
     pub fn time(this: &Transaction) -> u32;
     /// The player who sent resources for this transaction, or `None` if it was
@@ -255,8 +343,10 @@ extern "C" {
     /// an NPC terminal
     #[wasm_bindgen(method, getter)]
     pub fn recipient(this: &Transaction) -> Option<Player>;
+    /// The type of resource that was sent in this transaction
     #[wasm_bindgen(method, getter = resourceType)]
     pub fn resource_type(this: &Transaction) -> ResourceType;
+    /// The amount of the resource that was sent in this transaction
     #[wasm_bindgen(method, getter)]
     pub fn amount(this: &Transaction) -> u32;
     /// The room that sent resources for this transaction
@@ -277,22 +367,28 @@ extern "C" {
 
 #[wasm_bindgen]
 extern "C" {
+    /// A player taking part in a [`Transaction`].
     #[wasm_bindgen]
     #[derive(Debug)]
     pub type Player;
+    /// The player's username
     #[wasm_bindgen(method, getter)]
     pub fn username(this: &Player) -> JsString;
 }
 
 #[wasm_bindgen]
 extern "C" {
+    /// Information about the market order a [`Transaction`] was fulfilling.
     #[wasm_bindgen]
     #[derive(Debug)]
     pub type TransactionOrder;
+    /// The ID of the order
     #[wasm_bindgen(method, getter)]
     pub fn id(this: &TransactionOrder) -> JsString;
+    /// Whether the order was a buy or sell order
     #[wasm_bindgen(method, getter = type)]
     pub fn order_type(this: &TransactionOrder) -> OrderType;
+    /// The price per unit of the order
     #[wasm_bindgen(method, getter)]
     pub fn price(this: &TransactionOrder) -> f64;
 }
@@ -338,9 +434,14 @@ impl JsCollectionFromValue for MyOrder {
 
 #[wasm_bindgen]
 extern "C" {
+    /// A summary of one day of market activity for a resource, returned by
+    /// [`get_history`].
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Game.market.getHistory)
     #[wasm_bindgen]
-    #[derive(Debug)]
+    #[derive(Clone, Debug)]
     pub type OrderHistoryRecord;
+    /// The resource this record describes trades of
     #[wasm_bindgen(method, getter = resourceType)]
     pub fn resource_type(this: &OrderHistoryRecord) -> MarketResourceType;
     /// Calendar date in string format, eg "2018-12-31"
@@ -352,8 +453,79 @@ extern "C" {
     /// Total volume of this resource bought and sold on this day
     #[wasm_bindgen(method, getter)]
     pub fn volume(this: &OrderHistoryRecord) -> u32;
+    /// Average price per unit of this resource traded on this day
     #[wasm_bindgen(method, getter = avgPrice)]
     pub fn avg_price(this: &OrderHistoryRecord) -> f64;
+    /// Standard deviation of the price per unit of this resource traded on
+    /// this day
     #[wasm_bindgen(method, getter = stddevPrice)]
     pub fn stddev_price(this: &OrderHistoryRecord) -> f64;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constants::IntershardResourceType;
+
+    #[test]
+    fn order_params_serialization() {
+        let params = OrderParams::new(
+            OrderType::Sell,
+            MarketResourceType::Resource(ResourceType::Energy),
+            0.5,
+            10_000,
+        )
+        .room_name("W1N1".parse().unwrap());
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({
+                "type": "sell",
+                "resourceType": "energy",
+                "price": 0.5,
+                "totalAmount": 10_000,
+                "roomName": "W1N1",
+            })
+        );
+
+        let params = OrderParams::new(
+            OrderType::Buy,
+            MarketResourceType::IntershardResource(IntershardResourceType::Pixel),
+            25_000.,
+            3,
+        );
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({
+                "type": "buy",
+                "resourceType": "pixel",
+                "price": 25_000.,
+                "totalAmount": 3,
+            })
+        );
+    }
+
+    #[test]
+    fn best_price_picks_extreme_for_order_type() {
+        let orders = [
+            (1.5, 1000),
+            (0.9, 200),
+            (2.25, 50),
+            (0.1, 0),
+            (f64::NAN, 10),
+        ];
+
+        assert_eq!(
+            best_order_price(OrderType::Sell, orders.into_iter()),
+            Some(0.9)
+        );
+        assert_eq!(
+            best_order_price(OrderType::Buy, orders.into_iter()),
+            Some(2.25)
+        );
+        assert_eq!(best_order_price(OrderType::Sell, [].into_iter()), None);
+        assert_eq!(
+            best_order_price(OrderType::Buy, [(3.0, 0)].into_iter()),
+            None
+        );
+    }
+}
@@ -5,6 +5,8 @@
 use js_sys::JsString;
 use wasm_bindgen::prelude::*;
 
+use crate::constants::{ErrorCode, INTER_SHARD_MEMORY_SIZE_LIMIT};
+
 #[wasm_bindgen]
 extern "C" {
     type InterShardMemory;
@@ -38,6 +40,21 @@ pub fn set_local(val: &JsString) {
     InterShardMemory::set_local(val)
 }
 
+/// Overwrite the current shard's intershard memory segment with new
+/// contents, first checking that it's within the
+/// [`INTER_SHARD_MEMORY_SIZE_LIMIT`] of UTF-16 units.
+///
+/// Returns [`ErrorCode::Full`] without writing anything if `val` is too long.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#InterShardMemory.setLocal)
+pub fn try_set_local(val: &JsString) -> Result<(), ErrorCode> {
+    if !within_size_limit(val.length()) {
+        return Err(ErrorCode::Full);
+    }
+    InterShardMemory::set_local(val);
+    Ok(())
+}
+
 /// Get the data that another shard's code instance has written to its
 /// intershard memory segment.
 ///
@@ -45,3 +62,25 @@ pub fn set_local(val: &JsString) {
 pub fn get_remote(shard: &JsString) -> Option<JsString> {
     InterShardMemory::get_remote(shard)
 }
+
+fn within_size_limit(utf16_len: u32) -> bool {
+    utf16_len <= INTER_SHARD_MEMORY_SIZE_LIMIT
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn size_limit_boundary() {
+        assert!(within_size_limit(0));
+        assert!(within_size_limit(INTER_SHARD_MEMORY_SIZE_LIMIT));
+        assert!(!within_size_limit(INTER_SHARD_MEMORY_SIZE_LIMIT + 1));
+    }
+
+    #[test]
+    fn oversized_payload_exceeds_limit() {
+        let payload = "a".repeat(INTER_SHARD_MEMORY_SIZE_LIMIT as usize + 1);
+        assert!(!within_size_limit(payload.encode_utf16().count() as u32));
+    }
+}
@@ -37,6 +37,20 @@ pub struct JsHashMap<K, V> {
     _phantom: PhantomData<(K, V)>,
 }
 
+impl<K, V> JsHashMap<K, V> {
+    /// The number of entries in the map, counted from its keys without
+    /// converting any keys or values.
+    pub fn len(&self) -> usize {
+        Object::keys(self.map.unchecked_ref()).length() as usize
+    }
+
+    /// Whether the map has no entries, checked without converting any keys or
+    /// values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 impl<K, V> JsHashMap<K, V>
 where
     K: JsCollectionFromValue,
@@ -98,6 +98,7 @@
 // attribute working
 #![allow(deprecated)]
 
+pub mod cache;
 pub mod console;
 pub mod constants;
 pub mod enums;
@@ -2,12 +2,15 @@
 use std::ops::Range;
 
 mod cost_matrix;
+mod distance_transform;
 mod lodash_filter;
 mod object_id;
+mod pathing;
 mod position;
 mod room_coordinate;
 mod room_name;
 mod room_xy;
+mod stamp;
 mod terrain;
 
 /// Represents two constants related to room names.
@@ -18,7 +21,7 @@ mod terrain;
 /// Second, `-HALF_WORLD_SIZE` is the minimum representable room name
 /// coordinate, and `HALF_WORLD_SIZE - 1` is the maximum representable room name
 /// coordinate.
-const HALF_WORLD_SIZE: i32 = 128;
+pub(crate) const HALF_WORLD_SIZE: i32 = 128;
 
 /// Valid room name coordinates.
 const VALID_ROOM_NAME_COORDINATES: Range<i32> = -HALF_WORLD_SIZE..HALF_WORLD_SIZE;
@@ -32,6 +35,6 @@ const VALID_WORLD_POSITIONS: Range<i32> =
 use crate::ROOM_SIZE;
 
 pub use self::{
-    cost_matrix::*, lodash_filter::*, object_id::*, position::*, room_coordinate::*, room_name::*,
-    room_xy::*, terrain::*,
+    cost_matrix::*, distance_transform::*, lodash_filter::*, object_id::*, pathing::*, position::*,
+    room_coordinate::*, room_name::*, room_xy::*, stamp::*, terrain::*,
 };
@@ -95,6 +95,58 @@ impl LocalCostMatrix {
             .enumerate()
             .map(|(idx, val)| (linear_index_to_xy(idx), val))
     }
+
+    /// Set the cost of every tile in the rectangle between two corners,
+    /// inclusive.
+    ///
+    /// The corners may be given in any order; the rectangle always covers the
+    /// tiles between them, and can't extend past the room since both corners
+    /// are valid room coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use screeps::local::{LocalCostMatrix, RoomXY};
+    ///
+    /// let mut lcm = LocalCostMatrix::new();
+    /// let top_left = RoomXY::checked_new(10, 10).unwrap();
+    /// let bottom_right = RoomXY::checked_new(12, 11).unwrap();
+    /// lcm.fill_rect(top_left, bottom_right, u8::MAX);
+    ///
+    /// assert_eq!(lcm.get(RoomXY::checked_new(11, 11).unwrap()), u8::MAX);
+    /// assert_eq!(lcm.get(RoomXY::checked_new(13, 11).unwrap()), 0);
+    /// assert_eq!(lcm.iter().filter(|(_, cost)| *cost == u8::MAX).count(), 6);
+    /// ```
+    pub fn fill_rect(&mut self, top_left: RoomXY, bottom_right: RoomXY, cost: u8) {
+        let (min_x, max_x) = min_max(top_left.x.u8(), bottom_right.x.u8());
+        let (min_y, max_y) = min_max(top_left.y.u8(), bottom_right.y.u8());
+
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                // SAFETY: both coordinates are between those of valid `RoomXY`s
+                self.set(unsafe { RoomXY::unchecked_new(x, y) }, cost);
+            }
+        }
+    }
+
+    /// Set the cost of each of the given positions, such as those of creeps
+    /// that should be pathed around.
+    ///
+    /// As with indexing by [`Position`], the room of each position is
+    /// ignored.
+    pub fn block_positions(&mut self, positions: &[Position], cost: u8) {
+        for pos in positions {
+            self[*pos] = cost;
+        }
+    }
+}
+
+fn min_max(a: u8, b: u8) -> (u8, u8) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
 }
 
 impl From<LocalCostMatrix> for Vec<u8> {
@@ -204,3 +256,50 @@ mod serde_impls {
         Ok(bits_slice.try_into().unwrap())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local::RoomName;
+
+    #[test]
+    fn fill_rect_covers_exactly_the_rectangle() {
+        let mut matrix = LocalCostMatrix::new();
+        let corner_a = RoomXY::checked_new(47, 3).unwrap();
+        let corner_b = RoomXY::checked_new(49, 0).unwrap();
+        // corners given as bottom left and top right
+        matrix.fill_rect(corner_a, corner_b, 20);
+
+        for (xy, cost) in matrix.iter() {
+            let inside = (47..=49).contains(&xy.x.u8()) && (0..=3).contains(&xy.y.u8());
+            assert_eq!(cost, if inside { 20 } else { 0 }, "at {xy}");
+        }
+
+        let single = RoomXY::checked_new(25, 25).unwrap();
+        matrix.fill_rect(single, single, 5);
+        assert_eq!(matrix.iter().filter(|(_, cost)| *cost == 5).count(), 1);
+        assert_eq!(matrix.get(single), 5);
+    }
+
+    #[test]
+    fn block_positions_sets_each_tile() {
+        let mut matrix = LocalCostMatrix::new_with_value(1);
+        let room = "W1N1".parse::<RoomName>().unwrap();
+        let positions: Vec<Position> = [(3, 4), (40, 2), (3, 4)]
+            .into_iter()
+            .map(|(x, y)| {
+                let xy = RoomXY::checked_new(x, y).unwrap();
+                Position::new(xy.x, xy.y, room)
+            })
+            .collect();
+
+        matrix.block_positions(&positions, u8::MAX);
+
+        assert_eq!(
+            matrix.iter().filter(|(_, cost)| *cost == u8::MAX).count(),
+            2
+        );
+        assert_eq!(matrix[positions[0]], u8::MAX);
+        assert_eq!(matrix[positions[1]], u8::MAX);
+    }
+}
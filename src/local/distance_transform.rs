@@ -0,0 +1,155 @@
+use crate::constants::ROOM_SIZE;
+
+use super::{LocalCostMatrix, RoomXY};
+
+/// Offsets of the neighbors already visited when scanning row by row from the
+/// top left of the room.
+const FORWARD_NEIGHBORS: [(i8, i8); 4] = [(-1, -1), (0, -1), (1, -1), (-1, 0)];
+
+/// Offsets of the neighbors already visited when scanning row by row from the
+/// bottom right of the room.
+const BACKWARD_NEIGHBORS: [(i8, i8); 4] = [(1, 1), (0, 1), (-1, 1), (1, 0)];
+
+/// Compute the linear distance from every tile in a room to the nearest wall.
+///
+/// Tiles in `walls` with a cost of 255 (impassable, as in a
+/// [`CostMatrix`]) are walls, and get a distance of 0. The area outside of
+/// the room is also treated as walls, so tiles on the room edge have a
+/// distance of at most 1.
+///
+/// This is the usual starting point for finding open areas when planning room
+/// layouts; a tile with a distance of `n` is the center of an open square of
+/// size `2n - 1`.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{distance_transform, LocalCostMatrix, RoomXY};
+///
+/// let mut walls = LocalCostMatrix::new();
+/// walls.set(RoomXY::checked_new(10, 10).unwrap(), 255);
+///
+/// let distances = distance_transform(&walls);
+/// assert_eq!(distances.get(RoomXY::checked_new(10, 10).unwrap()), 0);
+/// assert_eq!(distances.get(RoomXY::checked_new(11, 9).unwrap()), 1);
+/// assert_eq!(distances.get(RoomXY::checked_new(13, 11).unwrap()), 3);
+/// assert_eq!(distances.get(RoomXY::checked_new(0, 30).unwrap()), 1);
+/// assert_eq!(distances.get(RoomXY::checked_new(25, 25).unwrap()), 15);
+/// ```
+///
+/// [`CostMatrix`]: crate::objects::CostMatrix
+pub fn distance_transform(walls: &LocalCostMatrix) -> LocalCostMatrix {
+    let mut distances = LocalCostMatrix::new();
+
+    for (xy, cost) in walls.iter() {
+        if cost != u8::MAX {
+            distances.set(xy, u8::MAX);
+        }
+    }
+
+    let forward = (0..ROOM_SIZE).flat_map(|y| (0..ROOM_SIZE).map(move |x| (x, y)));
+    relax(&mut distances, forward, &FORWARD_NEIGHBORS);
+
+    let backward = (0..ROOM_SIZE)
+        .rev()
+        .flat_map(|y| (0..ROOM_SIZE).rev().map(move |x| (x, y)));
+    relax(&mut distances, backward, &BACKWARD_NEIGHBORS);
+
+    distances
+}
+
+fn relax(
+    distances: &mut LocalCostMatrix,
+    order: impl Iterator<Item = (u8, u8)>,
+    neighbors: &[(i8, i8)],
+) {
+    for (x, y) in order {
+        let xy = RoomXY::checked_new(x, y).expect("scan stays within the room");
+        let current = distances.get(xy);
+        if current == 0 {
+            continue;
+        }
+
+        let nearest = neighbors
+            .iter()
+            .map(|&(dx, dy)| {
+                // the area outside of the room counts as a wall
+                x.checked_add_signed(dx)
+                    .zip(y.checked_add_signed(dy))
+                    .and_then(|(x, y)| RoomXY::checked_new(x, y).ok())
+                    .map_or(0, |neighbor| distances.get(neighbor))
+            })
+            .min()
+            .unwrap_or(0);
+
+        distances.set(xy, current.min(nearest.saturating_add(1)));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::checked_new(x, y).unwrap()
+    }
+
+    #[test]
+    fn open_room_is_distance_to_edge() {
+        let distances = distance_transform(&LocalCostMatrix::new());
+
+        for (xy, distance) in distances.iter() {
+            assert_eq!(distance, xy.range_to_edge() + 1, "at {xy}");
+        }
+        assert_eq!(distances.get(xy(24, 24)), 25);
+    }
+
+    #[test]
+    fn distances_around_walls() {
+        let mut walls = LocalCostMatrix::new();
+        // a short vertical wall, plus a single pillar
+        for y in 20..=22 {
+            walls.set(xy(20, y), u8::MAX);
+        }
+        walls.set(xy(26, 21), u8::MAX);
+        // ordinary costs aren't walls
+        walls.set(xy(23, 21), 10);
+
+        let distances = distance_transform(&walls);
+
+        // hand-computed distances along row 21, from the wall to the pillar
+        let row: Vec<u8> = (19..=27).map(|x| distances.get(xy(x, 21))).collect();
+        assert_eq!(row, [1, 0, 1, 2, 3, 2, 1, 0, 1]);
+
+        // diagonal neighbors of the wall's end are at range 1
+        assert_eq!(distances.get(xy(21, 23)), 1);
+        assert_eq!(distances.get(xy(19, 19)), 1);
+        // above the wall, both the wall and the pillar are 3 away
+        assert_eq!(distances.get(xy(23, 18)), 3);
+        assert_eq!(distances.get(xy(20, 17)), 3);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut walls = LocalCostMatrix::new();
+        for (x, y) in [(3, 7), (10, 40), (33, 12), (34, 12), (45, 45), (25, 26)] {
+            walls.set(xy(x, y), u8::MAX);
+        }
+
+        let distances = distance_transform(&walls);
+        let wall_tiles: Vec<RoomXY> = walls
+            .iter()
+            .filter(|(_, cost)| *cost == u8::MAX)
+            .map(|(xy, _)| xy)
+            .collect();
+
+        for (tile, distance) in distances.iter() {
+            let to_edge = tile.range_to_edge() + 1;
+            let expected = wall_tiles
+                .iter()
+                .map(|wall| tile.get_range_to(*wall))
+                .fold(to_edge, u8::min);
+            assert_eq!(distance, expected, "at {tile}");
+        }
+    }
+}
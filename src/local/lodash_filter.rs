@@ -1,4 +1,4 @@
-use crate::MarketResourceType;
+use crate::{MarketResourceType, OrderType};
 
 use js_sys::Object;
 use wasm_bindgen::prelude::*;
@@ -14,6 +14,11 @@ extern "C" {
     #[wasm_bindgen(method, setter = resourceType)]
     pub fn resource_type(this: &LodashFilter, resource: MarketResourceType);
 
+    /// Set the order `type` to be returned, for use with
+    /// [`get_all_orders`].
+    #[wasm_bindgen(method, setter = type)]
+    pub fn order_type(this: &LodashFilter, order_type: OrderType);
+
     // todo add more setters for use with Room.find()
 }
 
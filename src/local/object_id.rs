@@ -78,7 +78,9 @@ impl<T> Clone for ObjectId<T> {
 }
 impl<T> fmt::Debug for ObjectId<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.raw.fmt(f)
+        f.debug_tuple("ObjectId")
+            .field(&self.raw.to_array_string())
+            .finish()
     }
 }
 impl<T> PartialEq for ObjectId<T> {
@@ -271,3 +273,19 @@ impl<T> JsCollectionFromValue for ObjectId<T> {
         val.parse().expect("valid id string")
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::Creep;
+
+    #[test]
+    fn debug_is_id_string() {
+        for id in ["0", "bc03381d32f6790", "06aebab343040c9baaa22322"] {
+            let parsed: ObjectId<Creep> = id.parse().unwrap();
+            assert_eq!(format!("{parsed}"), id);
+            assert_eq!(format!("{parsed:?}"), format!("ObjectId(\"{id}\")"));
+            assert!(format!("{:?}", RawObjectId::from(parsed)).contains(&format!("\"{id}\"")));
+        }
+    }
+}
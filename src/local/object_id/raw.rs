@@ -316,6 +316,30 @@ mod test {
         assert_eq!(ids_sort, ids_parsed);
     }
 
+    #[test]
+    fn many_ids_as_map_keys() {
+        use std::collections::{BTreeMap, HashMap};
+
+        // shift the counter into the upper 96 bits, with a 24-character length
+        let ids: Vec<RawObjectId> = (0..10_000u128)
+            .map(|n| RawObjectId::from_packed((n * 0x9e37_79b9) << 32 | 24))
+            .collect();
+
+        let mut hash_map = HashMap::new();
+        let mut btree_map = BTreeMap::new();
+        for (idx, id) in ids.iter().enumerate() {
+            hash_map.insert(*id, idx);
+            btree_map.insert(*id, idx);
+        }
+
+        assert_eq!(hash_map.len(), ids.len());
+        assert_eq!(btree_map.len(), ids.len());
+        for (idx, id) in ids.iter().enumerate() {
+            assert_eq!(hash_map.get(id), Some(&idx));
+            assert_eq!(btree_map.get(id), Some(&idx));
+        }
+    }
+
     const INVALID_IDS: &[&str] = &[
         // empty string
         "",
@@ -0,0 +1,342 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::constants::ROOM_AREA;
+
+use super::{linear_index_to_xy, xy_to_linear_index, LocalCostMatrix, Position, RoomXY};
+
+/// Options for [`find_path_local`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LocalPathOptions {
+    range: u32,
+    max_ops: u32,
+}
+
+impl Default for LocalPathOptions {
+    fn default() -> Self {
+        LocalPathOptions {
+            range: 0,
+            max_ops: 2000,
+        }
+    }
+}
+
+impl LocalPathOptions {
+    /// Sets the range from the goal at which the path ends - default `0`.
+    #[inline]
+    pub fn range(mut self, range: u32) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the maximum number of tiles to expand before giving up - default
+    /// `2000`.
+    #[inline]
+    pub fn max_ops(mut self, ops: u32) -> Self {
+        self.max_ops = ops;
+        self
+    }
+}
+
+/// Find the cheapest path between two positions in the same room, entirely in
+/// Rust, using A* over the given cost matrix.
+///
+/// Each value in `costs` is the cost of moving onto that tile, with 255
+/// marking an impassable tile, as with the costs a [`CostMatrix`] would hold
+/// after applying terrain: typically 1 for plains, 5 for swamps and 255 for
+/// walls. Unlike [`pathfinder::search`], no terrain is applied on top of the
+/// matrix, so it must already include it.
+///
+/// Returns the positions along the path, not including `origin` and ending
+/// within the configured range of `goal`, or `None` if the positions are in
+/// different rooms or no path is found within the configured maximum ops.
+///
+/// To cheaply estimate the distance before deciding whether to search, see
+/// [`manhattan_estimate`].
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{find_path_local, LocalCostMatrix, LocalPathOptions, Position, RoomXY};
+///
+/// let room = "W1N1".parse().unwrap();
+/// let pos = |x, y| {
+///     let xy = RoomXY::checked_new(x, y).unwrap();
+///     Position::new(xy.x, xy.y, room)
+/// };
+///
+/// // plains everywhere, with a wall from (5, 0) down to (5, 8)
+/// let mut costs = LocalCostMatrix::new_with_value(1);
+/// for y in 0..=8 {
+///     costs.set(RoomXY::checked_new(5, y).unwrap(), 255);
+/// }
+///
+/// let path = find_path_local(pos(3, 3), pos(7, 3), &costs, LocalPathOptions::default())
+///     .expect("a path around the wall");
+/// assert_eq!(path.last(), Some(&pos(7, 3)));
+/// // down to row 9 to get around the wall, and back up
+/// assert_eq!(path.len(), 12);
+/// ```
+///
+/// [`CostMatrix`]: crate::objects::CostMatrix
+/// [`pathfinder::search`]: crate::pathfinder::search
+pub fn find_path_local(
+    origin: Position,
+    goal: Position,
+    costs: &LocalCostMatrix,
+    options: LocalPathOptions,
+) -> Option<Vec<Position>> {
+    let room_name = origin.room_name();
+    if goal.room_name() != room_name {
+        return None;
+    }
+
+    let path = find_path_xy(origin.xy(), goal.xy(), costs, options)?;
+    Some(
+        path.into_iter()
+            .map(|xy| Position::new(xy.x, xy.y, room_name))
+            .collect(),
+    )
+}
+
+/// A cheap estimate of the number of steps between two positions, as the sum
+/// of the horizontal and vertical distance between them, which works across
+/// rooms.
+///
+/// Creeps can move diagonally, so this can be up to twice the length of the
+/// shortest path in open terrain; [`Position::get_range_to`] is the lower
+/// bound [`find_path_local`] uses as its heuristic. This is meant for quickly
+/// ranking or discarding candidates without path finding to each of them.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{manhattan_estimate, Position, RoomCoordinate};
+///
+/// let pos = |x, y, room: &str| {
+///     Position::new(
+///         RoomCoordinate::new(x).unwrap(),
+///         RoomCoordinate::new(y).unwrap(),
+///         room.parse().unwrap(),
+///     )
+/// };
+///
+/// assert_eq!(
+///     manhattan_estimate(pos(10, 10, "W1N1"), pos(13, 14, "W1N1")),
+///     7
+/// );
+/// // one step from the east edge of W1N1 into the west edge of W0N1
+/// assert_eq!(
+///     manhattan_estimate(pos(49, 10, "W1N1"), pos(0, 10, "W0N1")),
+///     1
+/// );
+/// ```
+pub fn manhattan_estimate(from: Position, to: Position) -> u32 {
+    let (from_x, from_y) = from.world_coords();
+    let (to_x, to_y) = to.world_coords();
+    from_x.abs_diff(to_x) + from_y.abs_diff(to_y)
+}
+
+fn find_path_xy(
+    origin: RoomXY,
+    goal: RoomXY,
+    costs: &LocalCostMatrix,
+    options: LocalPathOptions,
+) -> Option<Vec<RoomXY>> {
+    // scale the range heuristic by the cheapest step available, so it never
+    // overestimates the remaining cost
+    let min_cost = costs
+        .iter()
+        .map(|(_, cost)| cost)
+        .filter(|cost| *cost != u8::MAX)
+        .min()? as u32;
+    let heuristic =
+        |xy: RoomXY| (xy.get_range_to(goal) as u32).saturating_sub(options.range) * min_cost;
+
+    let mut best_cost = vec![u32::MAX; ROOM_AREA];
+    let mut came_from = vec![None; ROOM_AREA];
+    let mut open = BinaryHeap::new();
+
+    best_cost[xy_to_linear_index(origin)] = 0;
+    open.push(Reverse((heuristic(origin), 0, xy_to_linear_index(origin))));
+
+    let mut ops = 0;
+    while let Some(Reverse((_, cost, idx))) = open.pop() {
+        if cost > best_cost[idx] {
+            // a cheaper route to this tile was already expanded
+            continue;
+        }
+
+        let xy = linear_index_to_xy(idx);
+        if xy.get_range_to(goal) as u32 <= options.range {
+            return Some(reconstruct(&came_from, origin, xy));
+        }
+
+        ops += 1;
+        if ops > options.max_ops {
+            return None;
+        }
+
+        for neighbor in xy.neighbors() {
+            let step_cost = costs.get(neighbor);
+            if step_cost == u8::MAX {
+                continue;
+            }
+
+            let neighbor_idx = xy_to_linear_index(neighbor);
+            let neighbor_cost = cost + step_cost as u32;
+            if neighbor_cost < best_cost[neighbor_idx] {
+                best_cost[neighbor_idx] = neighbor_cost;
+                came_from[neighbor_idx] = Some(xy);
+                open.push(Reverse((
+                    neighbor_cost + heuristic(neighbor),
+                    neighbor_cost,
+                    neighbor_idx,
+                )));
+            }
+        }
+    }
+
+    None
+}
+
+fn reconstruct(came_from: &[Option<RoomXY>], origin: RoomXY, end: RoomXY) -> Vec<RoomXY> {
+    let mut path = Vec::new();
+    let mut current = end;
+    while current != origin {
+        path.push(current);
+        current = came_from[xy_to_linear_index(current)].expect("every expanded tile has a parent");
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::checked_new(x, y).unwrap()
+    }
+
+    fn path_cost(costs: &LocalCostMatrix, path: &[RoomXY]) -> u32 {
+        path.iter().map(|xy| costs.get(*xy) as u32).sum()
+    }
+
+    #[test]
+    fn straight_and_diagonal_paths() {
+        let costs = LocalCostMatrix::new_with_value(1);
+        let options = LocalPathOptions::default();
+
+        // several paths tie here, any of them is fine
+        let straight = find_path_xy(xy(10, 10), xy(13, 10), &costs, options).unwrap();
+        assert_eq!(straight.len(), 3);
+        assert_eq!(straight.last(), Some(&xy(13, 10)));
+        // the only shortest diagonal path
+        assert_eq!(
+            find_path_xy(xy(10, 10), xy(13, 13), &costs, options),
+            Some(vec![xy(11, 11), xy(12, 12), xy(13, 13)])
+        );
+        assert_eq!(
+            find_path_xy(xy(10, 10), xy(10, 10), &costs, options),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn shortest_path_around_wall() {
+        let mut costs = LocalCostMatrix::new_with_value(1);
+        // a wall at x = 12 from y = 6 to y = 15, with a gap only at the top
+        for y in 6..=15 {
+            costs.set(xy(12, y), u8::MAX);
+        }
+        for x in 0..50 {
+            costs.set(xy(x, 16), u8::MAX);
+        }
+
+        let path = find_path_xy(xy(10, 12), xy(14, 12), &costs, LocalPathOptions::default())
+            .expect("path through the gap");
+
+        assert!(path.contains(&xy(12, 5)));
+        assert_eq!(path.last(), Some(&xy(14, 12)));
+        // up 7 rows to the gap, then back down 7
+        assert_eq!(path.len(), 14);
+        assert!(path.iter().all(|xy| costs.get(*xy) != u8::MAX));
+        // each step moves to an adjacent tile
+        let mut previous = xy(10, 12);
+        for step in &path {
+            assert_eq!(previous.get_range_to(*step), 1);
+            previous = *step;
+        }
+    }
+
+    #[test]
+    fn prefers_plains_over_swamp() {
+        let mut costs = LocalCostMatrix::new_with_value(1);
+        // a band of swamp between start and goal, with a plain gap further away
+        for y in 0..50 {
+            if y != 20 {
+                costs.set(xy(25, y), 5);
+            }
+        }
+
+        let direct = find_path_xy(xy(24, 10), xy(26, 10), &costs, LocalPathOptions::default())
+            .expect("path across the swamp");
+        // crossing the swamp directly costs 6, the detour costs more
+        assert_eq!(path_cost(&costs, &direct), 6);
+
+        let near_gap = find_path_xy(xy(24, 18), xy(26, 18), &costs, LocalPathOptions::default())
+            .expect("path through the gap");
+        assert!(near_gap.contains(&xy(25, 20)));
+        assert_eq!(path_cost(&costs, &near_gap), 4);
+    }
+
+    #[test]
+    fn manhattan_estimate_bounds_path_length() {
+        let room = "W1N1".parse().unwrap();
+        let pos = |xy: RoomXY| Position::new(xy.x, xy.y, room);
+        let costs = LocalCostMatrix::new_with_value(1);
+
+        for (from, to) in [
+            (xy(10, 10), xy(10, 10)),
+            (xy(10, 10), xy(20, 10)),
+            (xy(10, 10), xy(20, 20)),
+            (xy(40, 5), xy(3, 17)),
+        ] {
+            let estimate = manhattan_estimate(pos(from), pos(to));
+            let path_len = find_path_xy(from, to, &costs, LocalPathOptions::default())
+                .expect("path in open terrain")
+                .len() as u32;
+            assert!(estimate >= path_len);
+            assert!(estimate <= path_len * 2);
+        }
+
+        let east = Position::new(xy(5, 5).x, xy(5, 5).y, "W0N1".parse().unwrap());
+        assert_eq!(manhattan_estimate(pos(xy(45, 10)), east), 10 + 5);
+    }
+
+    #[test]
+    fn range_and_unreachable() {
+        let mut costs = LocalCostMatrix::new_with_value(1);
+        // box in the goal
+        for (x, y) in xy(30, 30)
+            .neighbors()
+            .iter()
+            .map(|xy| (xy.x.u8(), xy.y.u8()))
+        {
+            costs.set(xy(x, y), u8::MAX);
+        }
+
+        assert_eq!(
+            find_path_xy(xy(10, 10), xy(30, 30), &costs, LocalPathOptions::default()),
+            None
+        );
+
+        let options = LocalPathOptions::default().range(2);
+        let path = find_path_xy(xy(10, 10), xy(30, 30), &costs, options).expect("path in range");
+        assert_eq!(path.last().map(|end| end.get_range_to(xy(30, 30))), Some(2));
+        assert_eq!(path.len(), 18);
+
+        let options = LocalPathOptions::default().max_ops(5);
+        assert_eq!(find_path_xy(xy(10, 10), xy(40, 10), &costs, options), None);
+    }
+}
@@ -4,11 +4,14 @@
 //! written in JavaScript. All RoomPosition to RoomPosition operations in this
 //! file stay within Rust.
 use core::fmt::Debug;
-use std::{cmp::Ordering, fmt};
+use std::{cmp::Ordering, error::Error, fmt, str::FromStr};
 
 use crate::{constants::ROOM_SIZE, objects::RoomPosition, HasPosition};
 
-use super::{RoomCoordinate, RoomName, RoomXY, HALF_WORLD_SIZE};
+use super::{
+    linear_index_to_xy, xy_to_linear_index, OutOfBoundsError, RoomCoordinate, RoomName,
+    RoomNameParseError, RoomXY, HALF_WORLD_SIZE,
+};
 
 mod approximate_offsets;
 mod extra_math;
@@ -17,6 +20,8 @@ mod game_methods;
 mod pair_utils;
 mod world_utils;
 
+pub use self::world_utils::WorldPosition;
+
 /// Represents a position in a particular room in Screeps, stored in Rust
 /// memory.
 ///
@@ -228,6 +233,9 @@ impl fmt::Debug for Position {
     }
 }
 
+/// Formats the position as `[room W5N10 pos 23,45]`, the same as the
+/// game's `RoomPosition.toString`; this can be parsed back with
+/// [`str::parse`].
 impl fmt::Display for Position {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -240,6 +248,70 @@ impl fmt::Display for Position {
     }
 }
 
+/// Parses a position from the `[room W5N10 pos 23,45]` form it's displayed
+/// in.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{Position, RoomName};
+///
+/// let pos: Position = "[room E0S127 pos 0,49]".parse().unwrap();
+/// assert_eq!(pos.room_name(), "E0S127".parse::<RoomName>().unwrap());
+/// assert_eq!(pos.to_string(), "[room E0S127 pos 0,49]");
+///
+/// assert!("E0S127 0,49".parse::<Position>().is_err());
+/// assert!("[room E0S127 pos 0,50]".parse::<Position>().is_err());
+/// ```
+impl FromStr for Position {
+    type Err = PositionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (room_name, coords) = s
+            .strip_prefix("[room ")
+            .and_then(|s| s.strip_suffix(']'))
+            .and_then(|s| s.split_once(" pos "))
+            .ok_or(PositionParseError::InvalidFormat)?;
+        let (x, y) = coords
+            .split_once(',')
+            .ok_or(PositionParseError::InvalidFormat)?;
+
+        let room_name = room_name
+            .parse()
+            .map_err(PositionParseError::InvalidRoomName)?;
+        let coord = |coord: &str| {
+            let coord = coord
+                .parse()
+                .map_err(|_| PositionParseError::InvalidFormat)?;
+            RoomCoordinate::new(coord).map_err(PositionParseError::CoordinateOutOfBounds)
+        };
+
+        Ok(Position::new(coord(x)?, coord(y)?, room_name))
+    }
+}
+
+/// An error representing when a string can't be parsed into a [`Position`].
+#[derive(Clone, Debug)]
+pub enum PositionParseError {
+    InvalidFormat,
+    InvalidRoomName(RoomNameParseError),
+    CoordinateOutOfBounds(OutOfBoundsError),
+}
+
+impl Error for PositionParseError {}
+
+impl fmt::Display for PositionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PositionParseError::InvalidFormat => {
+                write!(f, "expected position formatted `[room <name> pos <x>,<y>]`")
+            }
+            PositionParseError::InvalidRoomName(e) => write!(f, "invalid room name: {e}"),
+            PositionParseError::CoordinateOutOfBounds(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct WorldPositionOutOfBoundsError(pub i32, pub i32);
 
@@ -277,11 +349,34 @@ impl Position {
         }
     }
 
+    /// Gets the packed representation of this position, which is also used by
+    /// the game engine and is stable across versions of this crate.
+    ///
+    /// From highest-order to lowest, the packed `u32` contains:
+    ///
+    /// - 1 byte: `room_x + 128`
+    /// - 1 byte: `room_y + 128`
+    /// - 1 byte: `x`, from 0 to 49
+    /// - 1 byte: `y`, from 0 to 49
+    ///
+    /// For `Wxx` rooms, `room_x = -xx - 1`. For `Exx` rooms, `room_x = xx`.
+    ///
+    /// For `Nyy` rooms, `room_y = -yy - 1`. For `Syy` rooms, `room_y = yy`.
+    ///
+    /// The upper 16 bits are the same as [`RoomName::packed_repr`].
     #[inline]
     pub const fn packed_repr(self) -> u32 {
         self.packed
     }
 
+    /// Creates a `Position` from its [packed representation].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the x or y coordinate is out of bounds; see
+    /// [`Position::checked_from_packed`] for a version which doesn't.
+    ///
+    /// [packed representation]: Position::packed_repr
     #[inline]
     pub fn from_packed(packed: u32) -> Self {
         let x = packed >> 8 & 0xFF;
@@ -291,43 +386,103 @@ impl Position {
         Position { packed }
     }
 
+    /// Creates a `Position` from its [packed representation], returning `None`
+    /// if the x or y coordinate is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use screeps::{Position, RoomCoordinate};
+    ///
+    /// let pos = Position::new(
+    ///     RoomCoordinate::try_from(24).unwrap(),
+    ///     RoomCoordinate::try_from(49).unwrap(),
+    ///     "E3S7".parse().unwrap(),
+    /// );
+    /// assert_eq!(Position::checked_from_packed(pos.packed_repr()), Some(pos));
+    /// // y of 50 is outside of the room
+    /// assert_eq!(Position::checked_from_packed(pos.packed_repr() + 1), None);
+    /// ```
+    ///
+    /// [packed representation]: Position::packed_repr
+    #[inline]
+    pub const fn checked_from_packed(packed: u32) -> Option<Self> {
+        let x = packed >> 8 & 0xFF;
+        let y = packed & 0xFF;
+        if x < ROOM_SIZE as u32 && y < ROOM_SIZE as u32 {
+            Some(Position { packed })
+        } else {
+            None
+        }
+    }
+
     /// Gets the horizontal coordinate of this position's room name.
     #[inline]
-    fn room_x(self) -> i32 {
+    const fn room_x(self) -> i32 {
         self.room_name().x_coord()
     }
 
     /// Gets the vertical coordinate of this position's room name.
     #[inline]
-    fn room_y(self) -> i32 {
+    const fn room_y(self) -> i32 {
         self.room_name().y_coord()
     }
 
     /// Gets this position's in-room x coordinate.
     #[inline]
-    pub fn x(self) -> RoomCoordinate {
+    pub const fn x(self) -> RoomCoordinate {
         // SAFETY: packed always contains a valid x coordinate
         unsafe { RoomCoordinate::unchecked_new((self.packed >> 8 & 0xFF) as u8) }
     }
 
     /// Gets this position's in-room y coordinate.
     #[inline]
-    pub fn y(self) -> RoomCoordinate {
+    pub const fn y(self) -> RoomCoordinate {
         // SAFETY: packed always contains a valid y coordinate
         unsafe { RoomCoordinate::unchecked_new((self.packed & 0xFF) as u8) }
     }
 
     /// Gets this position's in-room [`RoomXY`] coordinate pair
     #[inline]
-    pub fn xy(self) -> RoomXY {
+    pub const fn xy(self) -> RoomXY {
         // SAFETY: packed always contains a valid pair
         unsafe {
             RoomXY::unchecked_new((self.packed >> 8 & 0xFF) as u8, (self.packed & 0xFF) as u8)
         }
     }
 
+    /// Gets the index of this position's in-room coordinates in a flat array
+    /// of [`ROOM_AREA`] values, using [`xy_to_linear_index`].
+    ///
+    /// This is `x * 50 + y`, the same column-major order used by the game's
+    /// [`CostMatrix`] and by [`LocalCostMatrix`].
+    ///
+    /// [`ROOM_AREA`]: crate::constants::ROOM_AREA
+    /// [`CostMatrix`]: crate::objects::CostMatrix
+    /// [`LocalCostMatrix`]: super::LocalCostMatrix
     #[inline]
-    pub fn room_name(self) -> RoomName {
+    pub const fn room_linear_index(self) -> usize {
+        xy_to_linear_index(self.xy())
+    }
+
+    /// Creates a `Position` in the given room from an index into a flat array
+    /// of [`ROOM_AREA`] values, as returned by
+    /// [`Position::room_linear_index`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not less than [`ROOM_AREA`].
+    ///
+    /// [`ROOM_AREA`]: crate::constants::ROOM_AREA
+    #[inline]
+    pub fn from_room_linear_index(idx: usize, room_name: RoomName) -> Self {
+        let xy = linear_index_to_xy(idx);
+        Position::new(xy.x, xy.y, room_name)
+    }
+
+    /// Gets this position's [`RoomName`].
+    #[inline]
+    pub const fn room_name(self) -> RoomName {
         RoomName::from_packed(((self.packed >> 16) & 0xFFFF) as u16)
     }
 
@@ -482,7 +637,13 @@ pub mod serde_position_packed {
 
 #[cfg(test)]
 mod test {
-    use super::{Position, RoomCoordinate};
+    use super::{Position, RoomCoordinate, RoomName, RoomXY};
+    use crate::constants::ROOM_SIZE;
+
+    fn pos(x: u8, y: u8, room: &str) -> Position {
+        let xy = RoomXY::checked_new(x, y).unwrap();
+        Position::new(xy.x, xy.y, room.parse().unwrap())
+    }
 
     fn gen_test_positions() -> Vec<(u32, (RoomCoordinate, RoomCoordinate, &'static str))> {
         unsafe {
@@ -578,4 +739,127 @@ mod test {
             assert_eq!(pos.packed_repr(), packed);
         }
     }
+
+    #[test]
+    fn checked_from_packed_roundtrip() {
+        // a spread of rooms, including each corner of the world
+        let rooms = (0..=u16::MAX).step_by(257).chain([0, 0xFF, 0xFF00, 0xFFFF]);
+        for room_packed in rooms {
+            let room_name = RoomName::from_packed(room_packed);
+            for x in 0..ROOM_SIZE {
+                for y in 0..ROOM_SIZE {
+                    let xy = RoomXY::checked_new(x, y).unwrap();
+                    let pos = Position::new(xy.x, xy.y, room_name);
+                    assert_eq!(Position::checked_from_packed(pos.packed_repr()), Some(pos));
+                }
+            }
+        }
+
+        for (packed, _) in gen_test_positions().iter().copied() {
+            assert_eq!(
+                Position::checked_from_packed(packed),
+                Some(Position::from_packed(packed))
+            );
+        }
+
+        let valid = Position::from_packed(2021333800).packed_repr() & !0xFFFF;
+        for (x, y) in [(50, 0), (0, 50), (255, 10), (10, 255), (50, 50)] {
+            assert_eq!(
+                Position::checked_from_packed(valid | (x << 8) | y),
+                None,
+                "x {x}, y {y}"
+            );
+        }
+    }
+
+    #[test]
+    fn new_accessors_roundtrip() {
+        for (_, (x, y, name)) in gen_test_positions().iter().copied() {
+            let room_name = name.parse().unwrap();
+            let pos = Position::new(x, y, room_name);
+            assert_eq!(pos.x(), x);
+            assert_eq!(pos.y(), y);
+            assert_eq!(pos.xy(), RoomXY::new(x, y));
+            assert_eq!(pos.room_name(), room_name);
+        }
+    }
+
+    #[test]
+    fn ordering_is_reading_order() {
+        let expected = vec![
+            pos(10, 48, "W1N1"),
+            pos(49, 49, "W1N1"),
+            pos(0, 49, "E0N1"),
+            pos(5, 0, "W1N0"),
+            pos(49, 0, "W1N0"),
+            pos(0, 0, "E0N0"),
+            pos(3, 0, "E0N0"),
+            pos(2, 1, "E0N0"),
+            pos(0, 0, "E0S0"),
+        ];
+
+        let mut shuffled = expected.clone();
+        shuffled.reverse();
+        shuffled.swap(1, 6);
+        shuffled.swap(2, 4);
+        shuffled.sort();
+        assert_eq!(shuffled, expected);
+
+        // sorting again, or from a different starting order, gives the same result
+        let mut resorted = expected.clone();
+        resorted.rotate_left(4);
+        resorted.sort();
+        assert_eq!(resorted, expected);
+
+        // duplicates are removed when collected into an ordered set
+        let set: std::collections::BTreeSet<Position> =
+            expected.iter().chain(expected.iter()).copied().collect();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn display_parse_roundtrip() {
+        for (_, (x, y, name)) in gen_test_positions().iter().copied() {
+            let pos = Position::new(x, y, name.parse().unwrap());
+            assert_eq!(pos.to_string().parse::<Position>().unwrap(), pos);
+        }
+
+        let edge: Position = "[room E127S127 pos 49,0]".parse().unwrap();
+        assert_eq!(edge.x().u8(), 49);
+        assert_eq!(edge.y().u8(), 0);
+        assert_eq!(edge.to_string(), "[room E127S127 pos 49,0]");
+
+        for invalid in [
+            "",
+            "W1N1 10,10",
+            "[room W1N1 pos 10,10",
+            "[room W1N1 pos 10]",
+            "[room W1N1 pos -1,10]",
+            "[room W1N1 pos 10,50]",
+            "[room X1N1 pos 10,10]",
+        ] {
+            assert!(invalid.parse::<Position>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn room_linear_index_roundtrip() {
+        let room_name = "E3S7".parse().unwrap();
+
+        for (corner, idx) in [
+            (pos(0, 0, "E3S7"), 0),
+            (pos(0, 49, "E3S7"), 49),
+            (pos(49, 0, "E3S7"), 2450),
+            (pos(49, 49, "E3S7"), 2499),
+        ] {
+            assert_eq!(corner.room_linear_index(), idx);
+            assert_eq!(Position::from_room_linear_index(idx, room_name), corner);
+        }
+
+        for idx in 0..crate::constants::ROOM_AREA {
+            let pos = Position::from_room_linear_index(idx, room_name);
+            assert_eq!(pos.room_name(), room_name);
+            assert_eq!(pos.room_linear_index(), idx);
+        }
+    }
 }
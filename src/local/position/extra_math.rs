@@ -3,7 +3,11 @@
 use std::ops::{Add, Sub};
 
 use super::Position;
-use crate::{constants::Direction, local::position::WorldPositionOutOfBoundsError};
+use crate::{
+    constants::{Direction, ROOM_SIZE},
+    local::{position::WorldPositionOutOfBoundsError, RoomXY},
+    prelude::*,
+};
 
 impl Position {
     /// Returns whether this coordinate represents a room edge position (0 or
@@ -12,6 +16,236 @@ impl Position {
         self.xy().is_room_edge()
     }
 
+    /// Returns the distance from this position to the nearest edge of its
+    /// room.
+    pub fn range_to_edge(self) -> u8 {
+        self.xy().range_to_edge()
+    }
+
+    /// Returns an iterator over all positions within the given linear range of
+    /// this position, including this position itself, row by row.
+    ///
+    /// Unlike most functions on [`Position`], this doesn't cross into other
+    /// rooms; the area is clamped to this position's room.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use screeps::{Position, RoomCoordinate};
+    /// let room = "E5N5".parse().unwrap();
+    /// let pos = |x, y| {
+    ///     Position::new(
+    ///         RoomCoordinate::try_from(x).unwrap(),
+    ///         RoomCoordinate::try_from(y).unwrap(),
+    ///         room,
+    ///     )
+    /// };
+    ///
+    /// assert_eq!(pos(25, 25).positions_in_range(1).count(), 9);
+    /// assert_eq!(pos(25, 25).positions_in_range(3).count(), 49);
+    /// assert_eq!(pos(0, 0).positions_in_range(1).count(), 4);
+    /// assert_eq!(pos(49, 10).positions_in_range(2).count(), 15);
+    /// assert_eq!(pos(10, 10).positions_in_range(100).count(), 2500);
+    ///
+    /// assert!(pos(0, 0)
+    ///     .positions_in_range(1)
+    ///     .all(|p| p.room_name() == room && p.get_range_to(pos(0, 0)) <= 1));
+    /// ```
+    pub fn positions_in_range(self, range: u32) -> impl Iterator<Item = Position> {
+        let range = range.min(ROOM_SIZE as u32) as u8;
+        let room_name = self.room_name();
+        let (x, y) = (self.x().u8(), self.y().u8());
+        let max = ROOM_SIZE - 1;
+        let xs = x.saturating_sub(range)..=x.saturating_add(range).min(max);
+        let ys = y.saturating_sub(range)..=y.saturating_add(range).min(max);
+
+        ys.flat_map(move |y| xs.clone().map(move |x| (x, y)))
+            .filter_map(move |(x, y)| RoomXY::checked_new(x, y).ok())
+            .map(move |xy| Position::new(xy.x, xy.y, room_name))
+    }
+
+    /// Returns the position halfway between this position and another in the
+    /// same room, rounding halfway coordinates up, or `None` if the positions
+    /// are in different rooms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use screeps::{Position, RoomCoordinate};
+    /// let room = "W3S8".parse().unwrap();
+    /// let pos = |x, y| {
+    ///     Position::new(
+    ///         RoomCoordinate::try_from(x).unwrap(),
+    ///         RoomCoordinate::try_from(y).unwrap(),
+    ///         room,
+    ///     )
+    /// };
+    ///
+    /// assert_eq!(pos(10, 20).midpoint(pos(20, 40)), Some(pos(15, 30)));
+    /// assert_eq!(pos(10, 20).midpoint(pos(13, 20)), Some(pos(12, 20)));
+    /// assert_eq!(pos(10, 20).midpoint(pos(10, 20)), Some(pos(10, 20)));
+    ///
+    /// let other_room = Position::new(
+    ///     RoomCoordinate::try_from(10).unwrap(),
+    ///     RoomCoordinate::try_from(20).unwrap(),
+    ///     "W3S9".parse().unwrap(),
+    /// );
+    /// assert_eq!(pos(10, 20).midpoint(other_room), None);
+    /// ```
+    #[inline]
+    pub fn midpoint(self, other: Position) -> Option<Position> {
+        self.interpolate(other, 0.5)
+    }
+
+    /// Returns the position a fraction `t` of the way from this position to
+    /// another in the same room, rounded to the nearest tile.
+    ///
+    /// A `t` of 0 gives this position and 1 gives `other`; values outside of
+    /// that range extrapolate past either end. Returns `None` if the positions
+    /// are in different rooms, or the result would be outside of the room.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use screeps::{Position, RoomCoordinate};
+    /// let room = "W3S8".parse().unwrap();
+    /// let pos = |x, y| {
+    ///     Position::new(
+    ///         RoomCoordinate::try_from(x).unwrap(),
+    ///         RoomCoordinate::try_from(y).unwrap(),
+    ///         room,
+    ///     )
+    /// };
+    ///
+    /// assert_eq!(pos(0, 0).interpolate(pos(40, 20), 0.25), Some(pos(10, 5)));
+    /// assert_eq!(pos(0, 0).interpolate(pos(40, 20), 1.0), Some(pos(40, 20)));
+    /// assert_eq!(pos(10, 10).interpolate(pos(20, 10), 1.5), Some(pos(25, 10)));
+    /// assert_eq!(pos(10, 10).interpolate(pos(20, 10), -2.0), None);
+    /// ```
+    pub fn interpolate(self, other: Position, t: f32) -> Option<Position> {
+        if self.room_name() != other.room_name() || !t.is_finite() {
+            return None;
+        }
+
+        let lerp = |from: u8, to: u8| {
+            let value = (from as f32 + (to as f32 - from as f32) * t).round();
+            if (0.0..ROOM_SIZE as f32).contains(&value) {
+                Some(value as u8)
+            } else {
+                None
+            }
+        };
+        let x = lerp(self.x().u8(), other.x().u8())?;
+        let y = lerp(self.y().u8(), other.y().u8())?;
+
+        let xy = RoomXY::checked_new(x, y).ok()?;
+        Some(Position::new(xy.x, xy.y, self.room_name()))
+    }
+
+    /// Returns the candidate closest to this position by linear range, without
+    /// any calls into JavaScript.
+    ///
+    /// Range is measured as in [`Position::get_range_to`], so candidates in
+    /// other rooms are compared by their distance across room boundaries. As
+    /// with [`Position::find_closest_by_range`], the first of several
+    /// candidates at the same range is returned. Returns `None` if there are
+    /// no candidates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use screeps::{Position, RoomCoordinate};
+    /// let room = "W3S8".parse().unwrap();
+    /// let pos = |x, y| {
+    ///     Position::new(
+    ///         RoomCoordinate::try_from(x).unwrap(),
+    ///         RoomCoordinate::try_from(y).unwrap(),
+    ///         room,
+    ///     )
+    /// };
+    ///
+    /// let candidates = [pos(30, 30), pos(12, 18), pos(18, 12), pos(45, 5)];
+    /// // (12, 18) and (18, 12) are both at range 8; the first is picked
+    /// assert_eq!(pos(10, 10).closest_by_range(candidates), Some(pos(12, 18)));
+    /// assert_eq!(pos(40, 40).closest_by_range(candidates), Some(pos(30, 30)));
+    /// assert_eq!(pos(10, 10).closest_by_range(Vec::<Position>::new()), None);
+    /// ```
+    pub fn closest_by_range<T: HasPosition>(
+        self,
+        candidates: impl IntoIterator<Item = T>,
+    ) -> Option<T> {
+        let mut closest: Option<(u32, T)> = None;
+        for candidate in candidates {
+            let range = self.get_range_to(candidate.pos());
+            let is_closer = closest.as_ref().is_none_or(|(best, _)| range < *best);
+            if is_closer {
+                closest = Some((range, candidate));
+            }
+        }
+        closest.map(|(_, candidate)| candidate)
+    }
+
+    /// Returns the tiles along a straight line from this position to another
+    /// in the same room, including both endpoints, using Bresenham's line
+    /// algorithm.
+    ///
+    /// Each tile in the line is adjacent to the one before it. Returns `None`
+    /// if the positions are in different rooms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use screeps::{Position, RoomCoordinate};
+    /// let room = "W3S8".parse().unwrap();
+    /// let pos = |x, y| {
+    ///     Position::new(
+    ///         RoomCoordinate::try_from(x).unwrap(),
+    ///         RoomCoordinate::try_from(y).unwrap(),
+    ///         room,
+    ///     )
+    /// };
+    ///
+    /// assert_eq!(
+    ///     pos(10, 10).line_to(pos(13, 11)),
+    ///     Some(vec![pos(10, 10), pos(11, 10), pos(12, 11), pos(13, 11)])
+    /// );
+    /// assert_eq!(pos(10, 10).line_to(pos(10, 10)), Some(vec![pos(10, 10)]));
+    /// ```
+    pub fn line_to(self, target: Position) -> Option<Vec<Position>> {
+        if self.room_name() != target.room_name() {
+            return None;
+        }
+
+        let (mut x, mut y) = (self.x().u8() as i32, self.y().u8() as i32);
+        let (end_x, end_y) = (target.x().u8() as i32, target.y().u8() as i32);
+        let dx = (end_x - x).abs();
+        let dy = -(end_y - y).abs();
+        let step_x = if x < end_x { 1 } else { -1 };
+        let step_y = if y < end_y { 1 } else { -1 };
+        let mut error = dx + dy;
+
+        let mut line = Vec::with_capacity(dx.max(-dy) as usize + 1);
+        loop {
+            // every tile lies between the two (valid) endpoints
+            let xy = RoomXY::checked_new(x as u8, y as u8).ok()?;
+            line.push(Position::new(xy.x, xy.y, self.room_name()));
+            if x == end_x && y == end_y {
+                break;
+            }
+
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+        Some(line)
+    }
+
     /// Returns a new position offset from this position by the specified x
     /// coords and y coords.
     ///
@@ -229,3 +463,46 @@ impl Sub<Position> for Position {
         (mx - ox, my - oy)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local::{RoomCoordinate, RoomName};
+
+    fn pos(x: u8, y: u8) -> Position {
+        Position::new(
+            RoomCoordinate::try_from(x).unwrap(),
+            RoomCoordinate::try_from(y).unwrap(),
+            "E5N5".parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn line_to_is_contiguous_and_hits_both_endpoints() {
+        let cases = [
+            (pos(0, 0), pos(49, 49)),
+            (pos(3, 40), pos(45, 7)),
+            (pos(25, 25), pos(25, 2)),
+            (pos(48, 10), pos(1, 13)),
+            (pos(10, 10), pos(11, 30)),
+        ];
+        for (start, end) in cases {
+            for (from, to) in [(start, end), (end, start)] {
+                let line = from.line_to(to).unwrap();
+                assert_eq!(line.first(), Some(&from));
+                assert_eq!(line.last(), Some(&to));
+                assert_eq!(line.len() as u32, from.get_range_to(to) + 1);
+                for pair in line.windows(2) {
+                    assert_eq!(pair[0].get_range_to(pair[1]), 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn line_to_other_room_is_none() {
+        let room: RoomName = "E5N6".parse().unwrap();
+        let other = Position::new(pos(1, 1).x(), pos(1, 1).y(), room);
+        assert_eq!(pos(1, 1).line_to(other), None);
+    }
+}
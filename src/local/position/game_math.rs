@@ -1,6 +1,9 @@
 //! Utilities for doing math on [`Position`]s which are present in the
 //! JavaScript API.
-use crate::constants::Direction;
+use crate::{
+    constants::{Direction, ROOM_SIZE},
+    local::RoomName,
+};
 
 use super::Position;
 
@@ -51,6 +54,10 @@ impl Position {
     /// corresponding JavaScript method, `RoomPosition.getRangeTo` returns
     /// `Infinity` if given positions in different rooms.
     ///
+    /// Rooms are treated as laid out edge to edge, so the edge tiles of two
+    /// adjacent rooms facing each other are at range 1, and the same
+    /// coordinates in adjacent rooms are at range 50.
+    ///
     /// # Examples
     /// ```rust
     /// # use screeps::Position;
@@ -69,6 +76,30 @@ impl Position {
         dx.abs().max(dy.abs()) as u32
     }
 
+    /// Gets linear range to the given coordinates in a room, without
+    /// constructing a [`Position`] for them.
+    ///
+    /// This gives the same result as [`Position::get_range_to`] for a position
+    /// at those coordinates, including across rooms.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use screeps::Position;
+    /// // (5, 10) in E0N0
+    /// let pos = Position::from_world_coords(5, 10);
+    /// let room = pos.room_name();
+    ///
+    /// assert_eq!(pos.range_to_xy(8, 15, room), 5);
+    /// ```
+    #[doc(alias = "distance")]
+    #[inline]
+    pub fn range_to_xy(self, x: u8, y: u8, room: RoomName) -> u32 {
+        let room_size = ROOM_SIZE as i32;
+        let dx = self.world_x() - (room.x_coord() * room_size + x as i32);
+        let dy = self.world_y() - (room.y_coord() * room_size + y as i32);
+        dx.abs().max(dy.abs()) as u32
+    }
+
     /// Checks whether this position is in the given range of another position.
     ///
     /// Linear range (also called Chebyshev Distance) is an alternate
@@ -143,4 +174,67 @@ mod test {
         let b = Position::new(two, two, RoomName::from_coords(1, 1).unwrap());
         assert_eq!(a.get_direction_to(b), Some(Direction::BottomRight));
     }
+
+    fn pos(room: &str, x: u8, y: u8) -> Position {
+        Position::new(
+            x.try_into().unwrap(),
+            y.try_into().unwrap(),
+            room.parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_range_to() {
+        let cases = [
+            // same room, matching `RoomPosition.getRangeTo`
+            (pos("E0N0", 10, 10), pos("E0N0", 10, 10), 0),
+            (pos("E0N0", 10, 10), pos("E0N0", 11, 9), 1),
+            (pos("E0N0", 5, 10), pos("E0N0", 8, 15), 5),
+            (pos("W5S5", 0, 0), pos("W5S5", 49, 49), 49),
+            (pos("E3N7", 49, 2), pos("E3N7", 47, 40), 38),
+            // facing edges of adjacent rooms
+            (pos("W0N0", 49, 25), pos("E0N0", 0, 25), 1),
+            (pos("E0N0", 25, 49), pos("E0S0", 25, 0), 1),
+            (pos("E0N0", 49, 0), pos("E1N1", 0, 49), 1),
+            // same coordinates in neighboring rooms
+            (pos("E0N0", 10, 10), pos("E1N0", 10, 10), 50),
+            (pos("W1S1", 10, 10), pos("E0N0", 10, 10), 100),
+            // mixed offsets across several rooms
+            (pos("E0N0", 10, 10), pos("E2N1", 5, 45), 95),
+        ];
+
+        for (a, b, expected) in cases {
+            assert_eq!(a.get_range_to(b), expected, "{a} -> {b}");
+            assert_eq!(b.get_range_to(a), expected, "{b} -> {a}");
+            assert!(a.in_range_to(b, expected));
+            assert_eq!(a.in_range_to(b, expected.saturating_sub(1)), expected == 0);
+        }
+    }
+
+    #[test]
+    fn range_to_xy_matches_range_to_position() {
+        let rooms: Vec<RoomName> = ["E0N0", "W0N0", "E3S2", "W126N127", "E127S127"]
+            .iter()
+            .map(|name| name.parse().unwrap())
+            .collect();
+        let coords = [0, 1, 17, 48, 49];
+
+        for &from_room in &rooms {
+            for &to_room in &rooms {
+                for &x in &coords {
+                    for &y in &coords {
+                        let xy = |c: u8| RoomCoordinate::new(c).unwrap();
+                        let from = Position::new(xy(x), xy(y), from_room);
+                        let (tx, ty) = (49 - y, x);
+                        let to = Position::new(xy(tx), xy(ty), to_room);
+                        assert_eq!(
+                            from.range_to_xy(tx, ty, to_room),
+                            from.get_range_to(to),
+                            "{from} to {to}"
+                        );
+                    }
+                }
+            }
+        }
+    }
 }
@@ -35,9 +35,16 @@ impl Position {
     /// Creates a [`Flag`] at this position. If successful, returns the name of
     /// the created flag.
     ///
+    /// Returns [`ErrorCode::Full`] if you already have [`FLAGS_LIMIT`] flags,
+    /// [`ErrorCode::NameExists`] if a flag with the given name already exists,
+    /// or [`ErrorCode::InvalidArgs`] if the name is longer than
+    /// [`FLAG_NAME_MAX_LENGTH`] or a color is invalid.
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#RoomPosition.createFlag)
     ///
     /// [`Flag`]: crate::objects::Flag
+    /// [`FLAGS_LIMIT`]: crate::constants::FLAGS_LIMIT
+    /// [`FLAG_NAME_MAX_LENGTH`]: crate::constants::FLAG_NAME_MAX_LENGTH
     #[inline]
     pub fn create_flag(
         self,
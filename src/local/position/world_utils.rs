@@ -2,6 +2,61 @@ use crate::local::{position::WorldPositionOutOfBoundsError, VALID_WORLD_POSITION
 
 use super::{Position, HALF_WORLD_SIZE};
 
+/// A tile's absolute coordinates on the world grid, as returned by
+/// [`Position::world_coords`].
+///
+/// Rooms are laid out in one continuous grid, so the distance and direction
+/// between tiles in different rooms is plain arithmetic on these coordinates.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{Position, RoomCoordinate, RoomName, WorldPosition};
+///
+/// let pos = Position::new(
+///     RoomCoordinate::try_from(49).unwrap(),
+///     RoomCoordinate::try_from(20).unwrap(),
+///     "W0N3".parse().unwrap(),
+/// );
+/// let world = pos.to_world();
+/// assert_eq!(world, WorldPosition::new(-1, -180));
+///
+/// // the next tile east is over the border in E0N3
+/// let east = WorldPosition::new(world.x + 1, world.y)
+///     .to_position()
+///     .unwrap();
+/// assert_eq!(east.room_name(), "E0N3".parse::<RoomName>().unwrap());
+/// assert_eq!(u8::from(east.x()), 0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WorldPosition {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl WorldPosition {
+    /// Create a new `WorldPosition` from its coordinates.
+    #[inline]
+    pub const fn new(x: i32, y: i32) -> Self {
+        WorldPosition { x, y }
+    }
+
+    /// Converts back to a [`Position`], or `None` if the coordinates are
+    /// outside of the range `-128 * 50 .. +128 * 50` that positions can
+    /// represent.
+    #[inline]
+    pub fn to_position(self) -> Option<Position> {
+        Position::checked_from_world_coords(self.x, self.y).ok()
+    }
+}
+
+impl From<Position> for WorldPosition {
+    #[inline]
+    fn from(pos: Position) -> Self {
+        pos.to_world()
+    }
+}
+
 impl Position {
     /// Returns this position's horizontal "world coordinate".
     ///
@@ -38,6 +93,14 @@ impl Position {
         (self.world_x(), self.world_y())
     }
 
+    /// Returns this position's "world coordinates" as a [`WorldPosition`].
+    ///
+    /// See [`Position::world_coords`].
+    #[inline]
+    pub fn to_world(self) -> WorldPosition {
+        WorldPosition::new(self.world_x(), self.world_y())
+    }
+
     /// Creates a room position from world coords.
     ///
     /// # Panics
@@ -87,7 +150,7 @@ impl Position {
 
 #[cfg(test)]
 mod test {
-    use super::Position;
+    use super::{Position, WorldPosition};
     use crate::{
         local::{position::WorldPositionOutOfBoundsError, RoomCoordinate},
         ROOM_SIZE,
@@ -191,6 +254,38 @@ mod test {
         let _new_pos = pos + (5, 0);
     }
 
+    #[test]
+    fn adjacent_rooms_are_continuous() {
+        let pos = |x, y, room: &str| {
+            Position::new(
+                RoomCoordinate::try_from(x).unwrap(),
+                RoomCoordinate::try_from(y).unwrap(),
+                room.parse().unwrap(),
+            )
+        };
+
+        // across each border, including between the W/E and N/S halves
+        for (a, b, expected) in [
+            (pos(49, 10, "E1N1"), pos(0, 10, "E2N1"), (1, 0)),
+            (pos(49, 10, "W0N0"), pos(0, 10, "E0N0"), (1, 0)),
+            (pos(10, 49, "W3N0"), pos(10, 0, "W3S0"), (0, 1)),
+            (pos(0, 0, "E0S0"), pos(49, 49, "W0N0"), (-1, -1)),
+            (pos(25, 25, "W5S5"), pos(25, 25, "W4S5"), (50, 0)),
+        ] {
+            let (a_world, b_world) = (a.to_world(), b.to_world());
+            assert_eq!(
+                (b_world.x - a_world.x, b_world.y - a_world.y),
+                expected,
+                "{a} to {b}"
+            );
+            assert_eq!(a_world.to_position(), Some(a));
+            assert_eq!(WorldPosition::from(b).to_position(), Some(b));
+        }
+
+        assert_eq!(WorldPosition::new(6400, 0).to_position(), None);
+        assert_eq!(WorldPosition::new(0, -6401).to_position(), None);
+    }
+
     // don't run this test if debug assertions are enabled, it won't complete
     #[cfg(not(debug_assertions))]
     #[test]
@@ -53,11 +53,10 @@ impl RoomCoordinate {
     /// Calling this method with `coord >= ROOM_SIZE` can result in undefined
     /// behaviour when the resulting `RoomCoordinate` is used.
     #[inline]
-    pub unsafe fn unchecked_new(coord: u8) -> Self {
-        debug_assert!(
-            coord < ROOM_SIZE,
-            "Out of bounds unchecked coordinate: {coord}"
-        );
+    pub const unsafe fn unchecked_new(coord: u8) -> Self {
+        if cfg!(debug_assertions) && coord >= ROOM_SIZE {
+            unchecked_out_of_bounds(coord);
+        }
         RoomCoordinate(coord)
     }
 
@@ -83,6 +82,28 @@ impl RoomCoordinate {
         self.0 == 0 || self.0 == ROOM_SIZE - 1
     }
 
+    /// Get the distance from this coordinate to the nearest edge position (0
+    /// or 49).
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::local::RoomCoordinate;
+    ///
+    /// assert_eq!(RoomCoordinate::MIN.range_to_edge(), 0);
+    /// assert_eq!(RoomCoordinate::MAX.range_to_edge(), 0);
+    /// assert_eq!(RoomCoordinate::new(3).unwrap().range_to_edge(), 3);
+    /// assert_eq!(RoomCoordinate::new(46).unwrap().range_to_edge(), 3);
+    /// ```
+    pub const fn range_to_edge(self) -> u8 {
+        let to_max = ROOM_SIZE - 1 - self.0;
+        if self.0 < to_max {
+            self.0
+        } else {
+            to_max
+        }
+    }
+
     /// Get the coordinate adjusted by a certain value, returning `None` if the
     /// result is outside the valid range.
     ///
@@ -562,10 +583,57 @@ impl Neg for RoomOffset {
     }
 }
 
+/// Panics with a message including the out of bounds coordinate passed to
+/// [`RoomCoordinate::unchecked_new`]. Panics in const functions can't format
+/// integers, so the message is built by hand.
+#[cold]
+#[track_caller]
+const fn unchecked_out_of_bounds(coord: u8) -> ! {
+    const PREFIX: &[u8] = b"Out of bounds unchecked coordinate: ";
+
+    let mut message = [0; PREFIX.len() + 3];
+    let mut len = 0;
+    while len < PREFIX.len() {
+        message[len] = PREFIX[len];
+        len += 1;
+    }
+    if coord >= 100 {
+        message[len] = b'0' + coord / 100;
+        len += 1;
+    }
+    if coord >= 10 {
+        message[len] = b'0' + coord / 10 % 10;
+        len += 1;
+    }
+    message[len] = b'0' + coord % 10;
+    len += 1;
+
+    match std::str::from_utf8(message.split_at(len).0) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => panic!("Out of bounds unchecked coordinate"),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Out of bounds unchecked coordinate: 50")]
+    fn unchecked_new_reports_out_of_bounds_coord() {
+        // SAFETY: this is out of bounds, but debug assertions panic first
+        let _ = unsafe { RoomCoordinate::unchecked_new(50) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Out of bounds unchecked coordinate: 255")]
+    fn unchecked_new_reports_three_digit_coord() {
+        // SAFETY: this is out of bounds, but debug assertions panic first
+        let _ = unsafe { RoomCoordinate::unchecked_new(255) };
+    }
+
     #[test]
     fn checked_add() {
         for coord_inner in 0..ROOM_SIZE {
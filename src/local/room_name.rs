@@ -168,6 +168,10 @@ impl RoomName {
 
     /// Adds an `(x, y)` pair to this room's name.
     ///
+    /// Positive `x` moves east and positive `y` moves south, with no gap at
+    /// the axes: the room east of `W0N0` is `E0N0`, and the room south of
+    /// `W0N0` is `W0S0`.
+    ///
     /// # Errors
     /// Returns an error if the coordinates are outside of the valid room name
     /// bounds.
@@ -182,6 +186,38 @@ impl RoomName {
         Self::from_coords(new_x, new_y).ok()
     }
 
+    /// Whether this room is a highway room, with a 0 as the last digit of
+    /// either coordinate in its name.
+    ///
+    /// Highway rooms have no controller or sources, and are where power banks
+    /// and deposits appear.
+    #[inline]
+    pub const fn is_highway(&self) -> bool {
+        sector_offset(self.x_coord()) == 0 || sector_offset(self.y_coord()) == 0
+    }
+
+    /// Whether this room is the center room of a sector, with a 5 as the last
+    /// digit of both coordinates in its name.
+    ///
+    /// Center rooms have sources and a mineral without source keepers, and are
+    /// where portals appear.
+    #[inline]
+    pub const fn is_center(&self) -> bool {
+        sector_offset(self.x_coord()) == 5 && sector_offset(self.y_coord()) == 5
+    }
+
+    /// Whether this room is a source keeper room, one of the 8 rooms
+    /// surrounding the center room of a sector.
+    ///
+    /// Source keeper rooms have keeper lairs guarding their sources and
+    /// mineral.
+    #[inline]
+    pub const fn is_source_keeper(&self) -> bool {
+        let x = sector_offset(self.x_coord());
+        let y = sector_offset(self.y_coord());
+        4 <= x && x <= 6 && 4 <= y && y <= 6 && !(x == 5 && y == 5)
+    }
+
     /// Converts this RoomName into an efficient, stack-based string.
     ///
     /// This is equivalent to [`ToString::to_string`], but involves no
@@ -193,6 +229,13 @@ impl RoomName {
     }
 }
 
+/// Gets the last digit of the number shown in the room name for a coordinate.
+const fn sector_offset(coord: i32) -> i32 {
+    // `Wxx` and `Nyy` rooms have coordinates of `-xx - 1` and `-yy - 1`
+    let shown = if coord < 0 { -coord - 1 } else { coord };
+    shown % 10
+}
+
 impl From<RoomName> for JsValue {
     fn from(name: RoomName) -> JsValue {
         let array = name.to_array_string();
@@ -228,6 +271,12 @@ impl From<&RoomName> for JsString {
 /// An error representing when a string can't be parsed into a
 /// [`RoomName`].
 ///
+/// Strings which aren't formatted as a room name give
+/// [`RoomNameParseError::InvalidString`] (or
+/// [`RoomNameParseError::TooLarge`] if too long to be kept in the error),
+/// while well-formed names outside of the world give
+/// [`RoomNameParseError::PositionOutOfBounds`].
+///
 /// [`RoomName`]: struct.RoomName.html
 #[derive(Clone, Debug)]
 pub enum RoomNameConversionError {
@@ -442,10 +491,21 @@ impl fmt::Display for RoomNameParseError {
                 f,
                 "expected room name formatted `[ewEW][0-9]+[nsNS][0-9]+`, found `{string}`"
             ),
-            RoomNameParseError::PositionOutOfBounds { x_coord, y_coord } => write!(
-                f,
-                "expected room name with coords within -128..+128, found {x_coord}, {y_coord}"
-            ),
+            RoomNameParseError::PositionOutOfBounds { x_coord, y_coord } => {
+                let (x_dir, x) = match *x_coord {
+                    x if x < 0 => ('W', -(x as i64) - 1),
+                    x => ('E', x as i64),
+                };
+                let (y_dir, y) = match *y_coord {
+                    y if y < 0 => ('N', -(y as i64) - 1),
+                    y => ('S', y as i64),
+                };
+                write!(
+                    f,
+                    "expected room name with coords within -128..+128, found \
+                     `{x_dir}{x}{y_dir}{y}` with coords {x_coord}, {y_coord}"
+                )
+            }
         }
     }
 }
@@ -578,8 +638,47 @@ mod serde {
 
 #[cfg(test)]
 mod test {
+    use std::{collections::HashMap, hash::Hash};
+
+    use super::RoomNameParseError;
     use crate::RoomName;
 
+    #[test]
+    fn room_name_is_a_cheap_map_key() {
+        fn assert_key<T: Copy + Eq + Ord + Hash>() {}
+        assert_key::<RoomName>();
+        assert_eq!(std::mem::size_of::<RoomName>(), 2);
+
+        let room = RoomName::new("E3S4").unwrap();
+        let mut map = HashMap::new();
+        map.insert(room, 1);
+        assert_eq!(map.get(&room), Some(&1));
+    }
+
+    #[test]
+    fn parse_errors_report_reason() {
+        match "W300N5".parse::<RoomName>() {
+            Err(err @ RoomNameParseError::PositionOutOfBounds { x_coord, y_coord }) => {
+                assert_eq!((x_coord, y_coord), (-301, -6));
+                assert!(err.to_string().contains("`W300N5`"), "{err}");
+            }
+            other => panic!("expected out of bounds error, got {other:?}"),
+        }
+
+        match "X3N5".parse::<RoomName>() {
+            Err(err @ RoomNameParseError::InvalidString { string }) => {
+                assert_eq!(string.as_str(), "X3N5");
+                assert!(err.to_string().contains("`X3N5`"), "{err}");
+            }
+            other => panic!("expected invalid string error, got {other:?}"),
+        }
+
+        assert!(matches!(
+            "W1N1W1N1W1N1".parse::<RoomName>(),
+            Err(RoomNameParseError::TooLarge { length: 12 })
+        ));
+    }
+
     #[test]
     fn test_string_equality() {
         use super::RoomName;
@@ -597,6 +696,47 @@ mod test {
         }
     }
 
+    #[test]
+    fn room_classification() {
+        // (name, highway, center, source keeper)
+        let cases = [
+            ("E10N10", true, false, false),
+            ("W0N0", true, false, false),
+            ("E0S7", true, false, false),
+            ("W20N3", true, false, false),
+            ("E5N5", false, true, false),
+            ("W15S25", false, true, false),
+            ("E4N5", false, false, true),
+            ("W6S6", false, false, true),
+            ("E14S16", false, false, true),
+            ("E5N3", false, false, false),
+            ("W3N7", false, false, false),
+            ("E21N19", false, false, false),
+        ];
+
+        for (name, highway, center, source_keeper) in cases {
+            let room = RoomName::new(name).unwrap();
+            assert_eq!(room.is_highway(), highway, "{name}");
+            assert_eq!(room.is_center(), center, "{name}");
+            assert_eq!(room.is_source_keeper(), source_keeper, "{name}");
+        }
+    }
+
+    #[test]
+    fn checked_add_crosses_axes() {
+        let room = |name: &str| RoomName::new(name).unwrap();
+
+        assert_eq!(room("W0N3").checked_add((1, 0)), Some(room("E0N3")));
+        assert_eq!(room("E0N3").checked_add((-1, 0)), Some(room("W0N3")));
+        assert_eq!(room("E4N0").checked_add((0, 1)), Some(room("E4S0")));
+        assert_eq!(room("E4S0").checked_add((0, -1)), Some(room("E4N0")));
+        assert_eq!(room("W1S1").checked_add((2, -2)), Some(room("E0N0")));
+        assert_eq!(room("E2N2").checked_add((-5, 5)), Some(room("W2S2")));
+
+        assert_eq!(room("E127S0").checked_add((1, 0)), None);
+        assert_eq!(room("W5N127").checked_add((0, -1)), None);
+    }
+
     #[test]
     fn checked_add() {
         let w0n0 = RoomName::new("W0N0").unwrap();
@@ -93,7 +93,7 @@ impl RoomXY {
     /// Calling this method with `x >= ROOM_SIZE` or `y >= ROOM_SIZE` can
     /// result in undefined behaviour when the resulting `RoomXY` is used.
     #[inline]
-    pub unsafe fn unchecked_new(x: u8, y: u8) -> Self {
+    pub const unsafe fn unchecked_new(x: u8, y: u8) -> Self {
         RoomXY {
             x: RoomCoordinate::unchecked_new(x),
             y: RoomCoordinate::unchecked_new(y),
@@ -106,6 +106,32 @@ impl RoomXY {
         self.x.is_room_edge() || self.y.is_room_edge()
     }
 
+    /// Get the distance from this coordinate pair to the nearest edge position
+    /// of the room.
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::local::RoomXY;
+    ///
+    /// let corners = [(0, 0), (0, 49), (49, 0), (49, 49)];
+    /// for (x, y) in corners {
+    ///     assert_eq!(RoomXY::checked_new(x, y).unwrap().range_to_edge(), 0);
+    /// }
+    ///
+    /// assert_eq!(RoomXY::checked_new(3, 20).unwrap().range_to_edge(), 3);
+    /// assert_eq!(RoomXY::checked_new(24, 25).unwrap().range_to_edge(), 24);
+    /// ```
+    pub const fn range_to_edge(self) -> u8 {
+        let x = self.x.range_to_edge();
+        let y = self.y.range_to_edge();
+        if x < y {
+            x
+        } else {
+            y
+        }
+    }
+
     /// Get the coordinate adjusted by a certain value, returning `None` if the
     /// result is outside the valid room area.
     ///
@@ -0,0 +1,125 @@
+use crate::constants::StructureType;
+
+use super::RoomXY;
+
+/// A fixed pattern of structures to place together, such as a bunker layout
+/// or an extension cluster, positioned relative to an anchor tile within the
+/// pattern.
+///
+/// Use [`Room::place_stamp`] to create construction sites for every structure
+/// in a stamp at once.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{
+///     constants::StructureType,
+///     local::{RoomXY, Stamp},
+/// };
+///
+/// // a road with an extension on either side, anchored on the road
+/// let stamp = Stamp::new(
+///     vec![vec![
+///         Some(StructureType::Extension),
+///         Some(StructureType::Road),
+///         Some(StructureType::Extension),
+///     ]],
+///     (1, 0),
+/// );
+///
+/// let anchor = RoomXY::checked_new(10, 20).unwrap();
+/// assert_eq!(
+///     stamp.placements(anchor),
+///     [
+///         (RoomXY::checked_new(9, 20).ok(), StructureType::Extension),
+///         (RoomXY::checked_new(10, 20).ok(), StructureType::Road),
+///         (RoomXY::checked_new(11, 20).ok(), StructureType::Extension),
+///     ]
+/// );
+/// ```
+///
+/// [`Room::place_stamp`]: crate::objects::Room::place_stamp
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Stamp {
+    rows: Vec<Vec<Option<StructureType>>>,
+    anchor: (u8, u8),
+}
+
+impl Stamp {
+    /// Create a stamp from rows of cells, top to bottom, with each row listing
+    /// its cells left to right; `None` cells are left empty.
+    ///
+    /// `anchor` is the `(x, y)` of the cell within the stamp which is placed
+    /// at the anchor position.
+    pub fn new(rows: Vec<Vec<Option<StructureType>>>, anchor: (u8, u8)) -> Self {
+        Stamp { rows, anchor }
+    }
+
+    /// The `(x, y)` of the stamp's anchor cell.
+    pub fn anchor(&self) -> (u8, u8) {
+        self.anchor
+    }
+
+    /// Iterate over each structure in the stamp, with its `(x, y)` offset from
+    /// the anchor cell, in row order.
+    pub fn structures(&self) -> impl Iterator<Item = ((i32, i32), StructureType)> + '_ {
+        let (anchor_x, anchor_y) = (self.anchor.0 as i32, self.anchor.1 as i32);
+        self.rows.iter().enumerate().flat_map(move |(y, row)| {
+            row.iter().enumerate().filter_map(move |(x, cell)| {
+                cell.map(|ty| ((x as i32 - anchor_x, y as i32 - anchor_y), ty))
+            })
+        })
+    }
+
+    /// Get the room coordinates of each structure in the stamp with its
+    /// anchor cell placed at `anchor`, in row order.
+    ///
+    /// Structures which would fall outside of the room have no coordinates.
+    pub fn placements(&self, anchor: RoomXY) -> Vec<(Option<RoomXY>, StructureType)> {
+        self.structures()
+            .map(|((dx, dy), ty)| {
+                let x = u8::try_from(anchor.x.u8() as i32 + dx).ok();
+                let y = u8::try_from(anchor.y.u8() as i32 + dy).ok();
+                let xy = x.zip(y).and_then(|(x, y)| RoomXY::checked_new(x, y).ok());
+                (xy, ty)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn xy(x: u8, y: u8) -> Option<RoomXY> {
+        RoomXY::checked_new(x, y).ok()
+    }
+
+    #[test]
+    fn two_by_two_stamp_offsets() {
+        use StructureType::*;
+
+        let stamp = Stamp::new(
+            vec![vec![Some(Spawn), Some(Road)], vec![None, Some(Extension)]],
+            (1, 1),
+        );
+        assert_eq!(
+            stamp.structures().collect::<Vec<_>>(),
+            [((-1, -1), Spawn), ((0, -1), Road), ((0, 0), Extension)]
+        );
+        assert_eq!(
+            stamp.placements(xy(25, 30).unwrap()),
+            [
+                (xy(24, 29), Spawn),
+                (xy(25, 29), Road),
+                (xy(25, 30), Extension)
+            ]
+        );
+
+        // cells falling outside of the room have no coordinates
+        assert_eq!(
+            stamp.placements(xy(0, 49).unwrap()),
+            [(None, Spawn), (xy(0, 48), Road), (xy(0, 49), Extension)]
+        );
+    }
+}
@@ -20,17 +20,12 @@ pub struct LocalRoomTerrain {
 impl LocalRoomTerrain {
     /// Gets the terrain at the specified position in this room.
     pub fn get_xy(&self, xy: RoomXY) -> Terrain {
-        let byte = self.bits[xy.y][xy.x];
-        // not using Terrain::from_u8() because `0b11` value, wall+swamp, happens
-        // in commonly used server environments (notably the private server default
-        // map), and is special-cased in the engine code; we special-case it here
-        match byte & 0b11 {
-            0b00 => Terrain::Plain,
-            0b01 | 0b11 => Terrain::Wall,
-            0b10 => Terrain::Swamp,
-            // Should be optimized out
-            _ => unreachable!("all combinations of 2 bits are covered"),
-        }
+        terrain_from_byte(self.bits[xy.y][xy.x])
+    }
+
+    /// Counts the plain, swamp, and wall tiles in this room.
+    pub fn tile_counts(&self) -> TerrainCounts {
+        TerrainCounts::from_bytes(self.bits.iter().copied())
     }
 
     /// Creates a `LocalRoomTerrain` from the bytes that correspond to the
@@ -48,6 +43,45 @@ impl LocalRoomTerrain {
     }
 }
 
+/// The number of tiles of each type of terrain in a room, as returned by
+/// [`LocalRoomTerrain::tile_counts`] and [`RoomTerrain::tile_counts`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TerrainCounts {
+    pub plains: u32,
+    pub swamps: u32,
+    pub walls: u32,
+}
+
+impl TerrainCounts {
+    /// Counts the terrain in raw terrain bytes, as returned by
+    /// [`RoomTerrain::get_raw_buffer`].
+    pub(crate) fn from_bytes(bytes: impl IntoIterator<Item = u8>) -> Self {
+        bytes
+            .into_iter()
+            .fold(TerrainCounts::default(), |mut counts, byte| {
+                match terrain_from_byte(byte) {
+                    Terrain::Plain => counts.plains += 1,
+                    Terrain::Swamp => counts.swamps += 1,
+                    Terrain::Wall => counts.walls += 1,
+                }
+                counts
+            })
+    }
+}
+
+fn terrain_from_byte(byte: u8) -> Terrain {
+    // not using Terrain::from_u8() because `0b11` value, wall+swamp, happens
+    // in commonly used server environments (notably the private server default
+    // map), and is special-cased in the engine code; we special-case it here
+    match byte & 0b11 {
+        0b00 => Terrain::Plain,
+        0b01 | 0b11 => Terrain::Wall,
+        0b10 => Terrain::Swamp,
+        // Should be optimized out
+        _ => unreachable!("all combinations of 2 bits are covered"),
+    }
+}
+
 impl From<RoomTerrain> for LocalRoomTerrain {
     fn from(terrain: RoomTerrain) -> LocalRoomTerrain {
         // create an uninitialized array of the correct size
@@ -79,3 +113,40 @@ impl From<RoomTerrain> for LocalRoomTerrain {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tile_counts_cover_the_room() {
+        let mut bits = Box::new([0u8; ROOM_AREA]);
+        // walls along the edges, a patch of swamp, and a wall+swamp tile
+        for (idx, byte) in bits.iter_mut().enumerate() {
+            let (x, y) = (idx % 50, idx / 50);
+            if x == 0 || y == 0 || x == 49 || y == 49 {
+                *byte = 1;
+            } else if (10..20).contains(&x) && (10..15).contains(&y) {
+                *byte = 2;
+            }
+        }
+        bits[25 * 50 + 25] = 0b11;
+
+        let counts = LocalRoomTerrain::new_from_bits(bits).tile_counts();
+        assert_eq!(
+            counts,
+            TerrainCounts {
+                plains: 48 * 48 - 50 - 1,
+                swamps: 50,
+                walls: 196 + 1,
+            }
+        );
+        assert_eq!(
+            counts.plains + counts.swamps + counts.walls,
+            ROOM_AREA as u32
+        );
+
+        let empty = LocalRoomTerrain::new_from_bits(Box::new([0; ROOM_AREA]));
+        assert_eq!(empty.tile_counts().plains, ROOM_AREA as u32);
+    }
+}
@@ -5,6 +5,11 @@
 //! game object from a past tick will result in undefined behavior.
 mod impls;
 
+pub use impls::{
+    truncate_say, CombatStats, CreepSnapshot, Hostility, LargestTransfer, PartSummary, RoomIntel,
+    ScanScheduler,
+};
+
 pub use event::*;
 pub use game_types::*;
 pub use input::*;
@@ -15,9 +20,9 @@ pub use visual::*;
 /// Object wrappers representing data retrieved from room event logs.
 pub mod event {
     pub use super::impls::{
-        AttackEvent, AttackType, BuildEvent, Event, EventType, ExitEvent, HarvestEvent, HealEvent,
-        HealType, ObjectDestroyedEvent, PowerEvent, RepairEvent, ReserveControllerEvent,
-        TransferEvent, UpgradeControllerEvent,
+        AttackEvent, AttackType, BuildEvent, Event, EventType, EventTypeDiscriminant, ExitEvent,
+        HarvestEvent, HealEvent, HealType, ObjectDestroyedEvent, PowerEvent, RepairEvent,
+        ReserveControllerEvent, TransferEvent, UpgradeControllerEvent,
     };
 }
 
@@ -30,7 +35,9 @@ mod game_types {
 /// Object wrappers for simple javascript objects with known properties sent to
 /// game functions.
 pub mod input {
-    pub use super::impls::{FindPathOptions, JsFindPathOptions, MoveToOptions};
+    pub use super::impls::{
+        FindFilter, FindOptions, FindPathOptions, JsFindPathOptions, MoveToOptions,
+    };
 }
 
 /// Object wrappers for simple javascript objects with known properties returned
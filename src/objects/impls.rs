@@ -58,8 +58,8 @@ mod reactor;
 pub use self::{
     construction_site::ConstructionSite,
     cost_matrix::CostMatrix,
-    creep::{BodyPart, Creep},
-    creep_shared::MoveToOptions,
+    creep::{BodyPart, CombatStats, Creep, CreepSnapshot, Hostility, LargestTransfer, PartSummary},
+    creep_shared::{truncate_say, MoveToOptions},
     deposit::Deposit,
     flag::Flag,
     mineral::Mineral,
@@ -68,10 +68,10 @@ pub use self::{
     power_creep::{AccountPowerCreep, PowerCreep, PowerInfo},
     resource::Resource,
     room::{
-        AttackEvent, AttackType, BuildEvent, Event, EventType, ExitEvent, FindPathOptions,
-        HarvestEvent, HealEvent, HealType, JsFindPathOptions, ObjectDestroyedEvent, Path,
-        PowerEvent, RepairEvent, ReserveControllerEvent, Room, Step, TransferEvent,
-        UpgradeControllerEvent,
+        AttackEvent, AttackType, BuildEvent, Event, EventType, EventTypeDiscriminant, ExitEvent,
+        FindFilter, FindOptions, FindPathOptions, HarvestEvent, HealEvent, HealType,
+        JsFindPathOptions, ObjectDestroyedEvent, Path, PowerEvent, RepairEvent,
+        ReserveControllerEvent, Room, RoomIntel, Step, TransferEvent, UpgradeControllerEvent,
     },
     room_object::{Effect, RoomObject},
     room_position::RoomPosition,
@@ -90,7 +90,7 @@ pub use self::{
     structure_lab::StructureLab,
     structure_link::StructureLink,
     structure_nuker::StructureNuker,
-    structure_observer::StructureObserver,
+    structure_observer::{ScanScheduler, StructureObserver},
     structure_portal::{InterShardPortalDestination, PortalDestination, StructurePortal},
     structure_power_bank::StructurePowerBank,
     structure_power_spawn::StructurePowerSpawn,
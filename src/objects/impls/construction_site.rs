@@ -70,6 +70,12 @@ impl ConstructionSite {
         self.progress_total_internal()
     }
 
+    /// The amount of construction progress, and thus energy, still needed for
+    /// the structure to be completed.
+    pub fn energy_to_complete(&self) -> u32 {
+        self.progress_total().saturating_sub(self.progress())
+    }
+
     /// The type of structure being constructed.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Structure.structureType)
@@ -2,7 +2,7 @@ use js_sys::{Array, Object, Uint8Array};
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    local::{LocalCostMatrix, RoomXY},
+    local::{linear_index_to_xy, LocalCostMatrix, Position, RoomXY},
     prototypes::COST_MATRIX_PROTOTYPE,
     traits::{CostMatrixGet, CostMatrixSet},
 };
@@ -80,10 +80,55 @@ impl CostMatrix {
         matrix
     }
 
+    /// Sets a new value for the tile at the given [`Position`] in this
+    /// [`CostMatrix`]. Only the coordinates within the room are used; the
+    /// position's room name is ignored.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#PathFinder.CostMatrix.set)
+    pub fn set_pos(&self, pos: Position, cost: u8) {
+        let (x, y) = pos_coords(pos);
+        self.set(x, y, cost);
+    }
+
+    /// Get the value for the tile at the given [`Position`] in this
+    /// [`CostMatrix`]. Only the coordinates within the room are used; the
+    /// position's room name is ignored.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#PathFinder.CostMatrix.get)
+    pub fn get_pos(&self, pos: Position) -> u8 {
+        let (x, y) = pos_coords(pos);
+        self.get(x, y)
+    }
+
+    /// Iterate over the tiles with a nonzero cost in this [`CostMatrix`], as
+    /// `(x, y, cost)`.
+    ///
+    /// The matrix's data is copied across the memory boundary once, which is
+    /// much cheaper than calling [`CostMatrix::get`] for every tile.
+    pub fn iter_nonzero(&self) -> impl Iterator<Item = (u8, u8, u8)> {
+        nonzero_cells(self.get_bits().to_vec())
+    }
+
     // todo also a function that takes the unsafe view into wasm linear mem with
     // view for a matrix that'll easily go bad
 }
 
+/// The coordinates of a position within its room, as passed to the JS
+/// [`CostMatrix::get`] and [`CostMatrix::set`].
+fn pos_coords(pos: Position) -> (u8, u8) {
+    (pos.x().u8(), pos.y().u8())
+}
+
+fn nonzero_cells(bits: Vec<u8>) -> impl Iterator<Item = (u8, u8, u8)> {
+    bits.into_iter()
+        .enumerate()
+        .filter(|(_, cost)| *cost != 0)
+        .map(|(idx, cost)| {
+            let xy = linear_index_to_xy(idx);
+            (xy.x.u8(), xy.y.u8(), cost)
+        })
+}
+
 impl From<LocalCostMatrix> for CostMatrix {
     fn from(matrix: LocalCostMatrix) -> Self {
         CostMatrix::new_from_bits(matrix.get_bits())
@@ -101,3 +146,43 @@ impl CostMatrixGet for CostMatrix {
         CostMatrix::get(self, xy.x.u8(), xy.y.u8())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local::RoomName;
+
+    #[test]
+    fn pos_coords_ignore_room() {
+        let xy = RoomXY::checked_new(12, 34).unwrap();
+        let pos = Position::new(xy.x, xy.y, "W1N1".parse::<RoomName>().unwrap());
+        let other_room_pos = Position::new(xy.x, xy.y, "E5S5".parse::<RoomName>().unwrap());
+
+        assert_eq!(pos_coords(pos), (12, 34));
+        assert_eq!(pos_coords(other_room_pos), pos_coords(pos));
+
+        let corner = RoomXY::checked_new(49, 0).unwrap();
+        let corner_pos = Position::new(corner.x, corner.y, "W1N1".parse().unwrap());
+        assert_eq!(pos_coords(corner_pos), (49, 0));
+    }
+
+    #[test]
+    fn nonzero_cells_are_set_cells() {
+        let mut matrix = LocalCostMatrix::new();
+        assert_eq!(nonzero_cells(matrix.get_bits().to_vec()).count(), 0);
+
+        let set = [(0, 0, 1), (49, 0, 255), (3, 7, 5), (0, 49, 2), (49, 49, 10)];
+        for (x, y, cost) in set {
+            matrix.set(RoomXY::checked_new(x, y).unwrap(), cost);
+        }
+        // setting back to 0 clears a cell
+        matrix.set(RoomXY::checked_new(20, 20).unwrap(), 7);
+        matrix.set(RoomXY::checked_new(20, 20).unwrap(), 0);
+
+        let mut cells: Vec<(u8, u8, u8)> = nonzero_cells(matrix.get_bits().to_vec()).collect();
+        cells.sort_unstable();
+        let mut expected = set.to_vec();
+        expected.sort_unstable();
+        assert_eq!(cells, expected);
+    }
+}
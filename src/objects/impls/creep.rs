@@ -1,16 +1,28 @@
+use std::collections::HashSet;
+
 use js_sys::{Array, JsString};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    constants::{Direction, ErrorCode, Part, ResourceType},
+    constants::{
+        Boost, Direction, ErrorCode, Part, ResourceType, ATTACK_POWER, BUILD_POWER,
+        DISMANTLE_POWER, HEAL_POWER, RANGED_ATTACK_POWER, RANGED_HEAL_POWER,
+        SOURCE_KEEPER_USERNAME,
+    },
     objects::{
-        ConstructionSite, Owner, Resource, RoomObject, Store, Structure, StructureController,
+        truncate_say, ConstructionSite, FindPathOptions, Owner, Path, Resource, RoomObject, Source,
+        Store, Structure, StructureContainer, StructureController,
+    },
+    pathfinder::{
+        self, MultiRoomCostResult, RoomCostResult, SearchGoal, SearchOptions, SingleRoomCostResult,
     },
-    pathfinder::SingleRoomCostResult,
     prelude::*,
-    CostMatrix, MoveToOptions, RoomName, RoomPosition,
+    CostMatrix, MoveToOptions, ObjectId, Position, RoomName, RoomPosition,
 };
 
+use super::{room::serialize_steps, source::is_mining_spot, store::split_largest};
+
 #[cfg(feature = "seasonal-season-5")]
 use crate::objects::Reactor;
 
@@ -233,6 +245,14 @@ impl Creep {
         self.owner_internal()
     }
 
+    /// Classify this creep as [`Hostility::Friendly`] if it's yours or owned by
+    /// one of the given allies, [`Hostility::Neutral`] if it's a source keeper,
+    /// or otherwise [`Hostility::Hostile`], including other players' and
+    /// invaders' creeps.
+    pub fn hostility(&self, allies: &HashSet<String>) -> Hostility {
+        Hostility::classify(self.my(), &self.owner().username(), allies)
+    }
+
     /// What the creep said last tick.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.saying)
@@ -288,6 +308,19 @@ impl Creep {
         ErrorCode::result_from_i8(self.build_internal(target))
     }
 
+    /// Get the amount of energy a single [`Creep::build`] action on the given
+    /// [`ConstructionSite`] would spend this tick, limited by the energy
+    /// carried, the work remaining on the site, and the creep's active
+    /// [`Part::Work`] parts.
+    pub fn energy_for_build(&self, site: &ConstructionSite) -> u32 {
+        let build_capacity = u32::from(self.get_active_bodyparts(Part::Work)) * BUILD_POWER;
+
+        self.store()
+            .get_used_capacity(Some(ResourceType::Energy))
+            .min(site.energy_to_complete())
+            .min(build_capacity)
+    }
+
     /// Cancel an a successfully called creep function from earlier in the tick,
     /// with a [`JsString`] that must contain the JS version of the function
     /// name.
@@ -358,6 +391,54 @@ impl Creep {
         self.get_active_bodyparts_internal(ty)
     }
 
+    /// Get the total damage this creep deals per tick with
+    /// [`Creep::attack`], accounting for damaged parts and boosts.
+    pub fn attack_power(&self) -> u32 {
+        self.combat_stats().attack
+    }
+
+    /// Get the total damage this creep deals per tick with
+    /// [`Creep::ranged_attack`] against a single target, accounting for
+    /// damaged parts and boosts.
+    pub fn ranged_attack_power(&self) -> u32 {
+        self.combat_stats().ranged_attack
+    }
+
+    /// Get the attack, heal and dismantle power of this creep, and the damage
+    /// its tough parts can absorb, accounting for damaged parts and boosts.
+    ///
+    /// This reads the body array only once, so when more than one is needed
+    /// it's cheaper than calling [`Creep::attack_power`] and
+    /// [`Creep::ranged_attack_power`] separately, which each read it.
+    pub fn combat_stats(&self) -> CombatStats {
+        CombatStats::from_parts(
+            self.body_internal()
+                .iter()
+                .map(BodyPart::from)
+                .map(|body_part| (body_part.part(), body_part.boost(), body_part.hits())),
+        )
+    }
+
+    /// Take a serializable [`CreepSnapshot`] of this creep's current state,
+    /// for caching between ticks.
+    ///
+    /// The body and store are each read from the game only once.
+    pub fn snapshot(&self) -> CreepSnapshot {
+        CreepSnapshot {
+            id: self.try_id(),
+            pos: self.pos(),
+            hits: self.hits(),
+            hits_max: self.hits_max(),
+            store: self.store().contents(),
+            body: PartSummary::from_parts(
+                self.body_internal()
+                    .iter()
+                    .map(BodyPart::from)
+                    .map(|body_part| (body_part.part(), body_part.hits())),
+            ),
+        }
+    }
+
     /// Harvest from a [`Source`], [`Mineral`], or [`Deposit`] in melee range.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.harvest)
@@ -372,6 +453,31 @@ impl Creep {
         ErrorCode::result_from_i8(self.harvest_internal(target.as_ref()))
     }
 
+    /// Harvest a [`Source`] as a static miner, optionally standing on a
+    /// [`StructureContainer`] next to it.
+    ///
+    /// When the creep is on the container, the game drops any energy the
+    /// creep can't carry into the container, so no transfer is needed. This
+    /// returns [`ErrorCode::NotInRange`] without harvesting if the creep isn't
+    /// next to the source, or isn't standing on the given container.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.harvest)
+    ///
+    /// [`Source`]: crate::objects::Source
+    /// [`StructureContainer`]: crate::objects::StructureContainer
+    pub fn harvest_and_maybe_transfer(
+        &self,
+        source: &Source,
+        container: Option<&StructureContainer>,
+    ) -> Result<(), ErrorCode> {
+        check_mining_position(
+            self.pos(),
+            source.pos(),
+            container.map(|container| container.pos()),
+        )?;
+        self.harvest(source)
+    }
+
     /// Heal a [`Creep`] or [`PowerCreep`] in melee range, including itself.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.heal)
@@ -391,6 +497,67 @@ impl Creep {
         ErrorCode::result_from_i8(self.move_direction_internal(direction))
     }
 
+    /// Move one step toward whichever of the given targets is closest by path,
+    /// using a single [`pathfinder::search_many`] call with each target as a
+    /// goal at the given range.
+    ///
+    /// Returns the [`Direction`] moved in, or `None` if no target could be
+    /// reached, the creep is already within range of a target, or the move
+    /// failed.
+    pub fn move_to_nearest<T, F>(
+        &self,
+        targets: impl IntoIterator<Item = T>,
+        range: u32,
+        options: Option<SearchOptions<F>>,
+    ) -> Option<Direction>
+    where
+        T: HasPosition,
+        F: FnMut(RoomName) -> MultiRoomCostResult,
+    {
+        let pos = self.pos();
+        let goals = targets
+            .into_iter()
+            .map(|target| SearchGoal::new(target.pos(), range));
+
+        let results = pathfinder::search_many(pos, goals, options);
+        if results.incomplete() {
+            return None;
+        }
+
+        let next_step = *results.path().first()?;
+        let direction = pos.get_direction_to(next_step)?;
+        self.move_direction(direction).ok()?;
+
+        Some(direction)
+    }
+
+    /// Run `action` on the target, and if it fails with
+    /// [`ErrorCode::NotInRange`], move toward the target to within `range`
+    /// instead, returning the result of the move.
+    ///
+    /// Any other result from `action` is returned as-is without moving.
+    ///
+    /// ```no_run
+    /// use screeps::{Creep, ErrorCode, StructureController};
+    ///
+    /// fn upgrade(creep: &Creep, controller: &StructureController) -> Result<(), ErrorCode> {
+    ///     creep.do_or_move(controller, Creep::upgrade_controller, 3)
+    /// }
+    /// ```
+    pub fn do_or_move<T>(
+        &self,
+        target: &T,
+        action: impl FnOnce(&Creep, &T) -> Result<(), ErrorCode>,
+        range: u32,
+    ) -> Result<(), ErrorCode>
+    where
+        T: HasPosition,
+    {
+        or_move_closer(action(self, target), || {
+            self.move_to_with_options(target.pos(), Some(MoveToOptions::new().range(range)))
+        })
+    }
+
     /// Accept an attempt by another creep to pull this one.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.move)
@@ -406,6 +573,47 @@ impl Creep {
         ErrorCode::result_from_i8(self.move_by_path_internal(path))
     }
 
+    /// Find a path to the target within the creep's room, then move one step
+    /// along it, returning the path in serialized form.
+    ///
+    /// The returned string is in the same form as [`Room::serialize_path`], so
+    /// it can be stored in memory and passed to [`Creep::move_by_path`] on
+    /// later ticks to keep following the path without searching again. Any
+    /// [`FindPathOptions::serialize`] setting is ignored.
+    ///
+    /// Returns [`ErrorCode::NoPath`] without moving if no path is found.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.findPath)
+    ///
+    /// [`Room::serialize_path`]: crate::objects::Room::serialize_path
+    pub fn move_to_serialized<T, F, R>(
+        &self,
+        target: T,
+        options: Option<FindPathOptions<F, R>>,
+    ) -> Result<String, ErrorCode>
+    where
+        T: HasPosition,
+        F: FnMut(RoomName, CostMatrix) -> R,
+        R: RoomCostResult,
+    {
+        let room = self.room().expect("expected creep to be in a visible room");
+        let steps = match room.find_path(
+            &self.pos().into(),
+            &target.pos().into(),
+            options.map(|options| options.serialize(false)),
+        ) {
+            Path::Vectorized(steps) => steps,
+            Path::Serialized(_) => unreachable!("path serialization was disabled"),
+        };
+        if steps.is_empty() {
+            return Err(ErrorCode::NoPath);
+        }
+
+        let path = serialize_steps(&steps);
+        self.move_by_path(&JsValue::from_str(&path))?;
+        Ok(path)
+    }
+
     /// Whether to send an email notification when this creep is attacked.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.notifyWhenAttacked)
@@ -476,17 +684,23 @@ impl Creep {
     }
 
     /// Display a string in a bubble above the creep next tick. 10 character
-    /// limit.
+    /// limit; longer messages are shortened with [`truncate_say`].
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.say)
     pub fn say(&self, message: &str, public: bool) -> Result<(), ErrorCode> {
-        ErrorCode::result_from_i8(self.say_internal(message, public))
+        ErrorCode::result_from_i8(self.say_internal(truncate_say(message), public))
     }
 
     /// Add (or remove, using an empty string) a sign to a
-    /// [`StructureController`] in melee range.
+    /// [`StructureController`] in melee range. Text longer than
+    /// [`CONTROLLER_SIGN_MAX_LENGTH`] UTF-16 units is cut off by the game.
+    ///
+    /// The current sign can be read with [`StructureController::sign`], to
+    /// avoid signing a controller again with the same text.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.signController)
+    ///
+    /// [`CONTROLLER_SIGN_MAX_LENGTH`]: crate::constants::CONTROLLER_SIGN_MAX_LENGTH
     pub fn sign_controller(
         &self,
         target: &StructureController,
@@ -504,10 +718,103 @@ impl Creep {
         ErrorCode::result_from_i8(self.suicide_internal())
     }
 
+    /// Transfer all of the resource the creep is carrying the most of to a
+    /// target, returning which resource was transferred, the result, and the
+    /// other resources the creep is still carrying, or `None` if the creep is
+    /// empty.
+    ///
+    /// A creep can only transfer once per tick, so emptying a creep carrying
+    /// several resource types takes one call per type, on separate ticks; the
+    /// [`LargestTransfer::remaining`] resources are those left for later
+    /// ticks.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.transfer)
+    pub fn transfer_largest<T>(&self, target: &T) -> Option<LargestTransfer>
+    where
+        T: Transferable + ?Sized,
+    {
+        let (resource, remaining) = split_largest(self.store().contents())?;
+
+        Some(LargestTransfer {
+            resource,
+            result: ErrorCode::result_from_i8(self.transfer_internal(
+                target.as_ref(),
+                resource,
+                None,
+            )),
+            remaining,
+        })
+    }
+
+    /// Withdraw as much energy from a target as the creep can carry, returning
+    /// the amount withdrawn.
+    ///
+    /// The amount is the smaller of the creep's free capacity and the energy
+    /// in the target. If the creep is full or the target has no energy, this
+    /// returns [`ErrorCode::Full`] or [`ErrorCode::NotEnough`] without
+    /// attempting the withdrawal.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.withdraw)
+    pub fn withdraw_energy<T>(&self, target: &T) -> Result<u32, ErrorCode>
+    where
+        T: Withdrawable + HasStore + ?Sized,
+    {
+        let amount = energy_withdraw_amount(
+            self.store().get_free_capacity(Some(ResourceType::Energy)),
+            target.store().get_used_capacity(Some(ResourceType::Energy)),
+        )?;
+
+        ErrorCode::result_from_i8(self.withdraw_internal(
+            target.as_ref(),
+            ResourceType::Energy,
+            Some(amount),
+        ))
+        .map(|()| amount)
+    }
+
+    /// Pick the highest-scoring of a set of candidate targets, or `None` if
+    /// there are no candidates.
+    ///
+    /// `score` is called once for each candidate, along with its linear range
+    /// from the creep. Candidates scoring NaN are skipped, and the first
+    /// candidate wins ties.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::{constants::ResourceType, find, prelude::*, Creep};
+    ///
+    /// # let creep: Creep = todo!();
+    /// let room = creep.room().unwrap();
+    /// // prefer nearby piles, but travel further for a much larger one
+    /// let target = creep.find_best(
+    ///     room.find(find::DROPPED_RESOURCES, None),
+    ///     |resource, range| {
+    ///         if resource.resource_type() == ResourceType::Energy {
+    ///             resource.amount() as f64 / (range + 1) as f64
+    ///         } else {
+    ///             f64::NAN
+    ///         }
+    ///     },
+    /// );
+    /// ```
+    pub fn find_best<T, F>(&self, candidates: Vec<T>, score: F) -> Option<T>
+    where
+        T: HasPosition,
+        F: Fn(&T, u32) -> f64,
+    {
+        best_by_score(self.pos(), candidates, score)
+    }
+
     /// Upgrade a [`StructureController`] in range 3 using carried energy and
     /// the creep's work parts.
     ///
+    /// At room control level 8, the energy spent upgrading a controller is
+    /// capped per tick; see [`upgrade_energy_per_tick`] for sizing upgraders.
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.upgradeController)
+    ///
+    /// [`upgrade_energy_per_tick`]: crate::constants::upgrade_energy_per_tick
     pub fn upgrade_controller(&self, target: &StructureController) -> Result<(), ErrorCode> {
         ErrorCode::result_from_i8(self.upgrade_controller_internal(target))
     }
@@ -667,20 +974,541 @@ impl SharedCreepProperties for Creep {
     }
 }
 
+/// Moves closer with `move_closer` if an action's result was
+/// [`ErrorCode::NotInRange`].
+fn or_move_closer(
+    result: Result<(), ErrorCode>,
+    move_closer: impl FnOnce() -> Result<(), ErrorCode>,
+) -> Result<(), ErrorCode> {
+    match result {
+        Err(ErrorCode::NotInRange) => move_closer(),
+        result => result,
+    }
+}
+
+/// Checks that a miner is next to its source and, if it has one, on its
+/// container.
+fn check_mining_position(
+    creep_pos: Position,
+    source_pos: Position,
+    container_pos: Option<Position>,
+) -> Result<(), ErrorCode> {
+    let on_container = container_pos.is_none_or(|container_pos| creep_pos == container_pos);
+    if on_container && is_mining_spot(source_pos, creep_pos) {
+        Ok(())
+    } else {
+        Err(ErrorCode::NotInRange)
+    }
+}
+
+/// Caps a withdrawal at both the creep's free capacity and the amount
+/// available, returning the error the game would give if either is empty.
+fn energy_withdraw_amount(free_capacity: i32, available: u32) -> Result<u32, ErrorCode> {
+    if free_capacity <= 0 {
+        Err(ErrorCode::Full)
+    } else if available == 0 {
+        Err(ErrorCode::NotEnough)
+    } else {
+        Ok(available.min(free_capacity as u32))
+    }
+}
+
+/// Picks the candidate with the highest score, given its range from `origin`,
+/// preferring the first listed on ties.
+fn best_by_score<T: HasPosition>(
+    origin: Position,
+    candidates: Vec<T>,
+    score: impl Fn(&T, u32) -> f64,
+) -> Option<T> {
+    candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let candidate_score = score(&candidate, origin.get_range_to(candidate.pos()));
+            (!candidate_score.is_nan()).then_some((candidate, candidate_score))
+        })
+        .fold(
+            None,
+            |best: Option<(T, f64)>, (candidate, candidate_score)| match best {
+                Some((_, best_score)) if best_score >= candidate_score => best,
+                _ => Some((candidate, candidate_score)),
+            },
+        )
+        .map(|(candidate, _)| candidate)
+}
+
+/// How an object relates to you, based on its owner; see
+/// [`Creep::hostility`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Hostility {
+    /// Owned by you or one of your allies.
+    Friendly,
+    /// Owned by the source keeper NPC, which only attacks what comes within
+    /// range of the sources and minerals it guards.
+    Neutral,
+    /// Owned by another player who isn't one of your allies, or by invaders.
+    Hostile,
+}
+
+impl Hostility {
+    fn classify(my: bool, username: &str, allies: &HashSet<String>) -> Hostility {
+        if my || allies.contains(username) {
+            Hostility::Friendly
+        } else if username == SOURCE_KEEPER_USERNAME {
+            Hostility::Neutral
+        } else {
+            Hostility::Hostile
+        }
+    }
+}
+
+/// The combat capabilities of a creep's active body parts, accounting for
+/// boosts; see [`Creep::combat_stats`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CombatStats {
+    /// Damage dealt per tick with [`Creep::attack`].
+    pub attack: u32,
+    /// Damage dealt per tick to a single target with [`Creep::ranged_attack`].
+    pub ranged_attack: u32,
+    /// Hits healed per tick with [`Creep::heal`].
+    pub heal: u32,
+    /// Hits healed per tick with [`Creep::ranged_heal`].
+    pub ranged_heal: u32,
+    /// Structure hits removed per tick with [`Creep::dismantle`].
+    pub dismantle: u32,
+    /// Damage the creep's remaining tough parts can absorb before breaking,
+    /// accounting for boosts reducing the damage they take.
+    pub tough_hits: u32,
+}
+
+impl CombatStats {
+    fn from_parts(parts: impl IntoIterator<Item = (Part, Option<ResourceType>, u32)>) -> Self {
+        let mut stats = CombatStats::default();
+        for (part, boost, hits) in parts {
+            if hits == 0 {
+                continue;
+            }
+
+            let boost = boost.and_then(ResourceType::boost);
+            match part {
+                Part::Attack => {
+                    let multiplier = match boost {
+                        Some(Boost::Attack(multiplier)) => multiplier,
+                        _ => 1,
+                    };
+                    stats.attack += ATTACK_POWER * multiplier;
+                }
+                Part::RangedAttack => {
+                    let multiplier = match boost {
+                        Some(Boost::RangedAttack(multiplier)) => multiplier,
+                        _ => 1,
+                    };
+                    stats.ranged_attack += RANGED_ATTACK_POWER * multiplier;
+                }
+                Part::Heal => {
+                    let multiplier = match boost {
+                        Some(Boost::Heal(multiplier)) => multiplier,
+                        _ => 1,
+                    };
+                    stats.heal += HEAL_POWER * multiplier;
+                    stats.ranged_heal += RANGED_HEAL_POWER * multiplier;
+                }
+                Part::Work => {
+                    // work parts can instead be boosted for harvesting, building or
+                    // upgrading, which doesn't affect dismantling
+                    let multiplier = match boost {
+                        Some(Boost::Dismantle(multiplier)) => multiplier,
+                        _ => 1,
+                    };
+                    stats.dismantle += DISMANTLE_POWER * multiplier;
+                }
+                Part::Tough => {
+                    let damage_taken = match boost {
+                        Some(Boost::Tough(ratio)) => ratio,
+                        _ => 1.,
+                    };
+                    stats.tough_hits += (hits as f32 / damage_taken) as u32;
+                }
+                _ => {}
+            }
+        }
+        stats
+    }
+}
+
+/// The outcome of [`Creep::transfer_largest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LargestTransfer {
+    /// The resource the creep was carrying the most of, which it attempted to
+    /// transfer.
+    pub resource: ResourceType,
+    /// The result of the transfer.
+    pub result: Result<(), ErrorCode>,
+    /// The amount of each other resource the creep is carrying, which weren't
+    /// transferred this tick.
+    ///
+    /// This doesn't include any of the transferred resource left over when
+    /// the target couldn't hold all of it.
+    pub remaining: Vec<(ResourceType, u32)>,
+}
+
+/// A serializable copy of a creep's state at one tick; see
+/// [`Creep::snapshot`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreepSnapshot {
+    /// The creep's ID, or `None` if it began spawning this tick.
+    pub id: Option<ObjectId<Creep>>,
+    /// The creep's position.
+    pub pos: Position,
+    /// The creep's current hit points.
+    pub hits: u32,
+    /// The creep's maximum hit points.
+    pub hits_max: u32,
+    /// The amount of each resource the creep is carrying.
+    pub store: Vec<(ResourceType, u32)>,
+    /// The count of each type of part in the creep's body, in order of first
+    /// appearance.
+    pub body: Vec<PartSummary>,
+}
+
+/// The number of parts of one type in a creep's body; see
+/// [`CreepSnapshot::body`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartSummary {
+    /// The type of body part.
+    pub part: Part,
+    /// The number of parts of this type.
+    pub count: u32,
+    /// The number of parts of this type which have hits remaining.
+    pub active: u32,
+}
+
+impl PartSummary {
+    fn from_parts(parts: impl IntoIterator<Item = (Part, u32)>) -> Vec<Self> {
+        let mut summary: Vec<PartSummary> = Vec::new();
+        for (part, hits) in parts {
+            let index = match summary.iter().position(|entry| entry.part == part) {
+                Some(index) => index,
+                None => {
+                    summary.push(PartSummary {
+                        part,
+                        count: 0,
+                        active: 0,
+                    });
+                    summary.len() - 1
+                }
+            };
+            summary[index].count += 1;
+            if hits > 0 {
+                summary[index].active += 1;
+            }
+        }
+        summary
+    }
+}
+
 #[wasm_bindgen]
 extern "C" {
     /// A [`BodyPart`] of a creep.
     ///
-    /// [Screeps documentation](https://docs-ptr.screeps.com/api/#Creep.body)
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.body)
     #[wasm_bindgen]
+    #[derive(Clone, Debug)]
     pub type BodyPart;
 
+    /// The compound boosting this body part, or `None` if it isn't boosted.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.body)
     #[wasm_bindgen(method, getter)]
     pub fn boost(this: &BodyPart) -> Option<ResourceType>;
 
+    /// The type of this body part.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.body)
     #[wasm_bindgen(method, getter = type)]
     pub fn part(this: &BodyPart) -> Part;
 
+    /// The remaining hits of this body part, between 0 and 100; damage is
+    /// taken by parts from the front of the body first, and a part with 0
+    /// hits is inactive.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.body)
     #[wasm_bindgen(method, getter)]
     pub fn hits(this: &BodyPart) -> u32;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{constants::INVADER_USERNAME, local::RoomXY};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pile {
+        pos: Position,
+        amount: u32,
+    }
+
+    impl HasPosition for Pile {
+        fn pos(&self) -> Position {
+            self.pos
+        }
+    }
+
+    fn pos(x: u8, y: u8) -> Position {
+        let xy = RoomXY::checked_new(x, y).unwrap();
+        Position::new(xy.x, xy.y, "W1N1".parse().unwrap())
+    }
+
+    #[test]
+    fn mining_position_checks() {
+        let source = pos(20, 20);
+
+        assert_eq!(check_mining_position(pos(21, 20), source, None), Ok(()));
+        assert_eq!(
+            check_mining_position(pos(19, 21), source, Some(pos(19, 21))),
+            Ok(())
+        );
+        // next to the source, but off the container
+        assert_eq!(
+            check_mining_position(pos(21, 20), source, Some(pos(19, 21))),
+            Err(ErrorCode::NotInRange)
+        );
+        // on the container, but too far away
+        assert_eq!(
+            check_mining_position(pos(22, 20), source, Some(pos(22, 20))),
+            Err(ErrorCode::NotInRange)
+        );
+        assert_eq!(
+            check_mining_position(pos(25, 25), source, None),
+            Err(ErrorCode::NotInRange)
+        );
+    }
+
+    #[test]
+    fn best_by_score_weighs_range() {
+        let origin = pos(10, 10);
+        // piles at range 2, 5 and 20
+        let piles = vec![
+            Pile {
+                pos: pos(12, 10),
+                amount: 100,
+            },
+            Pile {
+                pos: pos(15, 15),
+                amount: 400,
+            },
+            Pile {
+                pos: pos(30, 10),
+                amount: 1000,
+            },
+        ];
+        let per_tick = |pile: &Pile, range: u32| pile.amount as f64 / range as f64;
+
+        let best = best_by_score(origin, piles.clone(), per_tick);
+        assert_eq!(best.map(|pile| pile.pos), Some(pos(15, 15)));
+
+        // nearest wins when only range matters
+        let best = best_by_score(origin, piles.clone(), |_, range| -(range as f64));
+        assert_eq!(best.map(|pile| pile.pos), Some(pos(12, 10)));
+
+        // NaN scores are skipped
+        let best = best_by_score(origin, piles, |pile, range| {
+            if pile.amount < 500 {
+                f64::NAN
+            } else {
+                per_tick(pile, range)
+            }
+        });
+        assert_eq!(best.map(|pile| pile.pos), Some(pos(30, 10)));
+
+        assert_eq!(best_by_score(origin, Vec::new(), per_tick), None);
+    }
+
+    #[test]
+    fn hostility_classification() {
+        let allies: HashSet<String> = ["ally".to_owned()].into_iter().collect();
+
+        assert_eq!(
+            Hostility::classify(true, "me", &allies),
+            Hostility::Friendly
+        );
+        assert_eq!(
+            Hostility::classify(false, "ally", &allies),
+            Hostility::Friendly
+        );
+        assert_eq!(
+            Hostility::classify(false, "stranger", &allies),
+            Hostility::Hostile
+        );
+        assert_eq!(
+            Hostility::classify(false, INVADER_USERNAME, &allies),
+            Hostility::Hostile
+        );
+        assert_eq!(
+            Hostility::classify(false, SOURCE_KEEPER_USERNAME, &allies),
+            Hostility::Neutral
+        );
+        assert_eq!(
+            Hostility::classify(false, "ally", &HashSet::new()),
+            Hostility::Hostile
+        );
+    }
+
+    #[test]
+    fn energy_withdraw_amount_caps_at_free_capacity() {
+        // a 300 capacity creep already carrying 50 energy, from a full container
+        assert_eq!(energy_withdraw_amount(250, 2_000), Ok(250));
+        // a nearly empty container
+        assert_eq!(energy_withdraw_amount(250, 40), Ok(40));
+        assert_eq!(energy_withdraw_amount(250, 250), Ok(250));
+
+        assert_eq!(energy_withdraw_amount(0, 2_000), Err(ErrorCode::Full));
+        assert_eq!(energy_withdraw_amount(-10, 2_000), Err(ErrorCode::Full));
+        assert_eq!(energy_withdraw_amount(250, 0), Err(ErrorCode::NotEnough));
+    }
+
+    #[test]
+    fn attack_power_of_mixed_body() {
+        use ResourceType::*;
+
+        let parts = [
+            (Part::Move, None, 100),
+            (Part::Attack, None, 100),
+            (Part::Attack, Some(UtriumHydride), 100),
+            // a ranged attack boost doesn't help melee attacks
+            (Part::Attack, Some(KeaniumOxide), 100),
+            (Part::Attack, Some(CatalyzedUtriumAcid), 0),
+            (Part::RangedAttack, None, 1),
+            (Part::RangedAttack, Some(KeaniumAlkalide), 100),
+            (Part::RangedAttack, Some(UtriumAcid), 100),
+            (Part::Work, None, 100),
+            (Part::Carry, Some(KeaniumHydride), 100),
+        ];
+        let stats = CombatStats::from_parts(parts);
+        assert_eq!(stats.attack, 30 + 30 * 2 + 30);
+        assert_eq!(stats.ranged_attack, 10 + 10 * 3 + 10);
+
+        let unarmed = CombatStats::from_parts([(Part::Work, None, 100), (Part::Move, None, 100)]);
+        assert_eq!(unarmed.attack, 0);
+        assert_eq!(unarmed.ranged_attack, 0);
+    }
+
+    #[test]
+    fn combat_stats_of_boosted_creep() {
+        use ResourceType::*;
+
+        let mut parts = Vec::new();
+        // nine intact tough parts, and one damaged down to 40 hits
+        parts.extend([(Part::Tough, Some(CatalyzedGhodiumAlkalide), 100); 9]);
+        parts.push((Part::Tough, Some(CatalyzedGhodiumAlkalide), 40));
+        parts.extend([(Part::RangedAttack, Some(CatalyzedKeaniumAlkalide), 100); 10]);
+        parts.extend([(Part::Heal, Some(CatalyzedLemergiumAlkalide), 100); 10]);
+        parts.extend([(Part::Attack, Some(CatalyzedUtriumAcid), 100); 5]);
+        parts.extend([(Part::Work, Some(CatalyzedZynthiumAcid), 100); 5]);
+        // a harvest boost doesn't help dismantling
+        parts.push((Part::Work, Some(CatalyzedUtriumAlkalide), 100));
+        // broken parts don't count
+        parts.push((Part::Heal, None, 0));
+        parts.push((Part::Attack, Some(CatalyzedUtriumAcid), 0));
+        parts.extend([(Part::Move, Some(CatalyzedZynthiumAlkalide), 100); 10]);
+
+        assert_eq!(
+            CombatStats::from_parts(parts),
+            CombatStats {
+                attack: 5 * 30 * 4,
+                ranged_attack: 10 * 10 * 4,
+                heal: 10 * 12 * 4,
+                ranged_heal: 10 * 4 * 4,
+                dismantle: 5 * 50 * 4 + 50,
+                // tough parts take 30% damage; 100 / 0.3 = 333, 40 / 0.3 = 133
+                tough_hits: 9 * 333 + 133,
+            }
+        );
+
+        assert_eq!(CombatStats::from_parts([]), CombatStats::default());
+        assert_eq!(
+            CombatStats::from_parts([(Part::Tough, None, 100), (Part::Heal, None, 100)]),
+            CombatStats {
+                heal: 12,
+                ranged_heal: 4,
+                tough_hits: 100,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn snapshot_summarizes_body_and_round_trips() {
+        let body = PartSummary::from_parts([
+            (Part::Tough, 0),
+            (Part::Move, 100),
+            (Part::Work, 40),
+            (Part::Move, 100),
+            (Part::Tough, 100),
+        ]);
+        assert_eq!(
+            body,
+            [
+                PartSummary {
+                    part: Part::Tough,
+                    count: 2,
+                    active: 1,
+                },
+                PartSummary {
+                    part: Part::Move,
+                    count: 2,
+                    active: 2,
+                },
+                PartSummary {
+                    part: Part::Work,
+                    count: 1,
+                    active: 1,
+                },
+            ]
+        );
+
+        let snapshot = CreepSnapshot {
+            id: Some("5bbcab1d9099fc012e6331c8".parse().unwrap()),
+            pos: {
+                let xy = RoomXY::checked_new(10, 20).unwrap();
+                Position::new(xy.x, xy.y, "E5N5".parse().unwrap())
+            },
+            hits: 340,
+            hits_max: 500,
+            store: vec![(ResourceType::Energy, 50), (ResourceType::Hydrogen, 5)],
+            body,
+        };
+        let json = serde_json::to_value(&snapshot).unwrap();
+        for field in ["id", "pos", "hits", "hitsMax", "store", "body"] {
+            assert!(json.get(field).is_some(), "missing {field}");
+        }
+        assert!(json.get("hits_max").is_none());
+        assert_eq!(json["hits"], 340);
+        assert_eq!(json["body"][0]["part"], "tough");
+
+        let parsed: CreepSnapshot = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, snapshot);
+    }
+
+    #[test]
+    fn or_move_closer_only_moves_when_out_of_range() {
+        let mut moved = false;
+        let result = or_move_closer(Err(ErrorCode::NotInRange), || {
+            moved = true;
+            Err(ErrorCode::Tired)
+        });
+        assert!(moved);
+        assert_eq!(result, Err(ErrorCode::Tired));
+
+        for action_result in [Ok(()), Err(ErrorCode::NotEnough)] {
+            let mut moved = false;
+            let result = or_move_closer(action_result, || {
+                moved = true;
+                Ok(())
+            });
+            assert!(!moved);
+            assert_eq!(result, action_result);
+        }
+    }
+}
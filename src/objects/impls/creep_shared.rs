@@ -1,10 +1,14 @@
 use js_sys::Object;
+use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
+use super::room::FindPathValues;
 use crate::{
-    local::RoomName,
+    constants::CREEP_SAY_MAX_LENGTH,
+    local::{Position, RoomName},
     objects::{CostMatrix, FindPathOptions, PolyStyle},
     pathfinder::SingleRoomCostResult,
+    traits::CostMatrixSet,
 };
 
 #[wasm_bindgen]
@@ -23,11 +27,6 @@ extern "C" {
 
     #[wasm_bindgen(method, setter = visualizePathStyle)]
     pub fn visualize_path_style(this: &JsMoveToOptions, style: &JsValue);
-
-    // todo this is wrong, the additional options are supposed to be added to the
-    // same object
-    #[wasm_bindgen(method, setter = heuristicWeight)]
-    pub fn find_path_options(this: &JsMoveToOptions, options: &JsValue);
 }
 
 impl JsMoveToOptions {
@@ -42,6 +41,27 @@ impl Default for JsMoveToOptions {
     }
 }
 
+/// Truncates a message to fit within [`CREEP_SAY_MAX_LENGTH`] UTF-16 code
+/// units, as counted by the game when displaying [`Creep::say`] and
+/// [`PowerCreep::say`] messages.
+///
+/// Unlike the game's own truncation, this never splits a character, so
+/// emoji and other characters outside the basic multilingual plane are either
+/// kept whole or dropped.
+///
+/// [`Creep::say`]: crate::objects::Creep::say
+/// [`PowerCreep::say`]: crate::objects::PowerCreep::say
+pub fn truncate_say(message: &str) -> &str {
+    let mut utf16_len = 0;
+    for (idx, c) in message.char_indices() {
+        utf16_len += c.len_utf16() as u32;
+        if utf16_len > CREEP_SAY_MAX_LENGTH {
+            return &message[..idx];
+        }
+    }
+    message
+}
+
 pub struct MoveToOptions<F>
 where
     F: FnMut(RoomName, CostMatrix) -> SingleRoomCostResult,
@@ -50,6 +70,8 @@ where
     pub(crate) serialize_memory: Option<bool>,
     pub(crate) no_path_finding: Option<bool>,
     pub(crate) visualize_path_style: Option<PolyStyle>,
+    pub(crate) ignore: Vec<Position>,
+    pub(crate) avoid: Vec<Position>,
     pub(crate) find_path_options: FindPathOptions<F, SingleRoomCostResult>,
 }
 
@@ -60,6 +82,8 @@ impl Default for MoveToOptions<fn(RoomName, CostMatrix) -> SingleRoomCostResult>
             serialize_memory: None,
             no_path_finding: None,
             visualize_path_style: None,
+            ignore: Vec::new(),
+            avoid: Vec::new(),
             find_path_options: FindPathOptions::default(),
         }
     }
@@ -114,6 +138,22 @@ where
         self
     }
 
+    /// Sets positions which are treated as walkable, regardless of any creeps
+    /// or structures on them, by setting their cost to 0 after the cost
+    /// callback has run. Default: empty.
+    pub fn ignore(mut self, positions: Vec<Position>) -> Self {
+        self.ignore = positions;
+        self
+    }
+
+    /// Sets positions which are treated as impassable, by setting their cost to
+    /// 255 after the cost callback has run. Takes priority over
+    /// [`MoveToOptions::ignore`]. Default: empty.
+    pub fn avoid(mut self, positions: Vec<Position>) -> Self {
+        self.avoid = positions;
+        self
+    }
+
     /// Sets cost callback - default `|_, _| {}`.
     pub fn cost_callback<F2>(self, cost_callback: F2) -> MoveToOptions<F2>
     where
@@ -124,13 +164,19 @@ where
             serialize_memory: self.serialize_memory,
             no_path_finding: self.no_path_finding,
             visualize_path_style: self.visualize_path_style,
+            ignore: self.ignore,
+            avoid: self.avoid,
             find_path_options: self.find_path_options.cost_callback(cost_callback),
         };
 
         new_options
     }
 
-    /// Sets maximum ops - default `2000`.
+    /// Sets the maximum pathfinding operations allowed when a new path is
+    /// needed, limiting the CPU spent on long moves - default `2000`.
+    ///
+    /// If no path to the target is found within the limit, the creep moves
+    /// along a partial path toward it instead.
     pub fn max_ops(mut self, ops: u32) -> Self {
         self.find_path_options.max_ops = Some(ops);
         self
@@ -148,7 +194,8 @@ where
         self
     }
 
-    /// Sets maximum rooms - default `16`, max `16`.
+    /// Sets the maximum rooms the path may pass through - default `16`, max
+    /// `16`.
     pub fn max_rooms(mut self, rooms: u8) -> Self {
         self.find_path_options.max_rooms = Some(rooms);
         self
@@ -165,7 +212,9 @@ where
         self
     }
 
-    /// Sets swamp cost - default `5`.
+    /// Sets swamp cost - default `5`. Setting this to the plain cost gives more
+    /// direct paths for creeps with enough move parts to cross swamps without
+    /// extra fatigue.
     pub fn swamp_cost(mut self, cost: u8) -> Self {
         self.find_path_options.swamp_cost = Some(cost);
         self
@@ -185,40 +234,197 @@ where
             serialize_memory: self.serialize_memory,
             no_path_finding: self.no_path_finding,
             visualize_path_style: self.visualize_path_style,
+            ignore: self.ignore,
+            avoid: self.avoid,
             find_path_options,
         }
     }
 
     pub(crate) fn into_js_options<CR>(self, callback: impl Fn(&JsMoveToOptions) -> CR) -> CR {
-        //
-        // Create JS object and set properties.
-        //
-
-        let js_options = JsMoveToOptions::new();
+        let values = self.values();
+
+        let ignore = self.ignore;
+        let avoid = self.avoid;
+        let find_path_options = self
+            .find_path_options
+            .map_cost_callback(|mut cost_callback| {
+                move |room_name: RoomName, cost_matrix: CostMatrix| {
+                    if ignore.is_empty() && avoid.is_empty() {
+                        return cost_callback(room_name, cost_matrix);
+                    }
+
+                    let default_matrix: CostMatrix =
+                        JsValue::clone(cost_matrix.as_ref()).unchecked_into();
+                    let mut matrix = match cost_callback(room_name, cost_matrix) {
+                        SingleRoomCostResult::CostMatrix(matrix) => matrix,
+                        SingleRoomCostResult::Default => default_matrix,
+                    };
+                    apply_ignore_and_avoid(&mut matrix, room_name, &ignore, &avoid);
+
+                    SingleRoomCostResult::CostMatrix(matrix)
+                }
+            });
+
+        // `moveTo` takes the `findPath` options on the same object as its own
+        find_path_options
+            .into_js_options_with(&values, |js_options| callback(js_options.unchecked_ref()))
+    }
 
-        if let Some(reuse_path) = self.reuse_path {
-            js_options.reuse_path(reuse_path);
+    /// The options other than the cost callback, as they're set on the JS
+    /// options object.
+    fn values(&self) -> MoveToValues {
+        MoveToValues {
+            reuse_path: self.reuse_path,
+            serialize_memory: self.serialize_memory,
+            no_path_finding: self.no_path_finding,
+            visualize_path_style: self.visualize_path_style.clone(),
+            find_path: self.find_path_options.values(),
         }
+    }
+}
 
-        if let Some(serialize_memory) = self.serialize_memory {
-            js_options.serialize_memory(serialize_memory);
-        }
+/// The options of a [`MoveToOptions`] other than the cost callback, with
+/// unset options left out so the game's defaults apply.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MoveToValues {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reuse_path: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    serialize_memory: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    no_path_finding: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visualize_path_style: Option<PolyStyle>,
+    #[serde(flatten)]
+    find_path: FindPathValues,
+}
 
-        if let Some(no_path_finding) = self.no_path_finding {
-            js_options.no_path_finding(no_path_finding);
+/// Sets the cost of each ignored position in the room to 0, then each avoided
+/// position to 255.
+fn apply_ignore_and_avoid(
+    matrix: &mut impl CostMatrixSet,
+    room_name: RoomName,
+    ignore: &[Position],
+    avoid: &[Position],
+) {
+    let overrides = ignore
+        .iter()
+        .map(|pos| (pos, 0))
+        .chain(avoid.iter().map(|pos| (pos, u8::MAX)));
+    for (pos, cost) in overrides {
+        if pos.room_name() == room_name {
+            matrix.set_xy(pos.xy(), cost);
         }
+    }
+}
 
-        if let Some(visualize_path_style) = self.visualize_path_style {
-            let style = serde_wasm_bindgen::to_value(&visualize_path_style)
-                .expect("expected to serialize visualize path style");
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local::{find_path_local, LocalCostMatrix, LocalPathOptions, RoomXY};
 
-            js_options.visualize_path_style(&style);
-        }
+    fn pos(x: u8, y: u8, room: &str) -> Position {
+        let xy = RoomXY::checked_new(x, y).unwrap();
+        Position::new(xy.x, xy.y, room.parse().unwrap())
+    }
 
-        self.find_path_options.into_js_options(|find_path_options| {
-            js_options.find_path_options(find_path_options);
+    #[test]
+    fn truncate_say_ascii() {
+        assert_eq!(truncate_say("hello"), "hello");
+        assert_eq!(truncate_say("0123456789"), "0123456789");
+        assert_eq!(truncate_say("0123456789abc"), "0123456789");
+    }
+
+    #[test]
+    fn truncate_say_emoji() {
+        // each of these emoji are two UTF-16 code units
+        assert_eq!(truncate_say("🚚🚚🚚🚚🚚"), "🚚🚚🚚🚚🚚");
+        assert_eq!(truncate_say("🚚🚚🚚🚚🚚🚚"), "🚚🚚🚚🚚🚚");
+        // the emoji would straddle the limit, so it's dropped entirely
+        assert_eq!(truncate_say("123456789🚚"), "123456789");
+        // multi-byte characters within the basic multilingual plane count once
+        assert_eq!(truncate_say("ééééééééééé"), "éééééééééé");
+    }
+
+    #[test]
+    fn avoided_tile_is_routed_around() {
+        let room_name = "W1N1".parse().unwrap();
+        let mut costs = LocalCostMatrix::new_with_value(1);
+        // a creep standing in the way, which should be walked through
+        costs.set(RoomXY::checked_new(12, 10).unwrap(), u8::MAX);
+
+        apply_ignore_and_avoid(
+            &mut costs,
+            room_name,
+            &[pos(12, 10, "W1N1"), pos(13, 10, "W1N1")],
+            &[
+                pos(11, 10, "W1N1"),
+                pos(13, 10, "W1N1"),
+                pos(12, 12, "W2N1"),
+            ],
+        );
+
+        assert_eq!(costs.get(RoomXY::checked_new(12, 10).unwrap()), 0);
+        assert_eq!(costs.get(RoomXY::checked_new(11, 10).unwrap()), u8::MAX);
+        // avoiding takes priority over ignoring
+        assert_eq!(costs.get(RoomXY::checked_new(13, 10).unwrap()), u8::MAX);
+        // positions in other rooms are left alone
+        assert_eq!(costs.get(RoomXY::checked_new(12, 12).unwrap()), 1);
+
+        let path = find_path_local(
+            pos(10, 10, "W1N1"),
+            pos(14, 10, "W1N1"),
+            &costs,
+            LocalPathOptions::default(),
+        )
+        .expect("path around the avoided tiles");
+        assert!(!path.contains(&pos(11, 10, "W1N1")));
+        assert!(!path.contains(&pos(13, 10, "W1N1")));
+        assert_eq!(path.last(), Some(&pos(14, 10, "W1N1")));
+    }
+
+    #[test]
+    fn js_options_are_one_flat_object() {
+        assert_eq!(
+            serde_json::to_value(MoveToOptions::new().values()).unwrap(),
+            serde_json::json!({})
+        );
+
+        let options = MoveToOptions::new()
+            .reuse_path(3)
+            .visualize_path_style(PolyStyle::default().stroke("#ffffff"))
+            .ignore_creeps(true)
+            .max_ops(200)
+            .plain_cost(2)
+            .swamp_cost(2);
+        assert_eq!(
+            serde_json::to_value(options.values()).unwrap(),
+            serde_json::json!({
+                "reusePath": 3,
+                "visualizePathStyle": { "stroke": "#ffffff" },
+                "ignoreCreeps": true,
+                "maxOps": 200,
+                "plainCost": 2,
+                "swampCost": 2,
+            })
+        );
+    }
 
-            callback(&js_options)
-        })
+    #[test]
+    fn pathfinding_limits_passed_to_find_path_options() {
+        let defaults = MoveToOptions::new();
+        // unset, so the game's defaults apply
+        assert_eq!(defaults.find_path_options.max_ops, None);
+        assert_eq!(defaults.find_path_options.max_rooms, None);
+
+        let limited = MoveToOptions::new().max_ops(200).max_rooms(2);
+        assert_eq!(limited.find_path_options.max_ops, Some(200));
+        assert_eq!(limited.find_path_options.max_rooms, Some(2));
+
+        // limits survive swapping in a cost callback
+        let with_callback = limited.cost_callback(|_, _| SingleRoomCostResult::Default);
+        assert_eq!(with_callback.find_path_options.max_ops, Some(200));
+        assert_eq!(with_callback.find_path_options.max_rooms, Some(2));
     }
 }
@@ -1,8 +1,10 @@
-use js_sys::JsString;
+use js_sys::{JsString, Object, Reflect};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use wasm_bindgen::prelude::*;
 
 use crate::{
     constants::Color,
+    local::Position,
     objects::{RoomObject, RoomPosition},
     prelude::*,
 };
@@ -50,6 +52,12 @@ extern "C" {
     #[wasm_bindgen(method, getter = name)]
     pub fn name_jsstring(this: &Flag) -> JsString;
 
+    /// Secondary color of the flag.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Flag.secondaryColor)
+    #[wasm_bindgen(method, getter = secondaryColor)]
+    pub fn secondary_color(this: &Flag) -> Color;
+
     /// Remove the flag.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Flag.remove)
@@ -67,6 +75,54 @@ extern "C" {
     /// [Screeps documentation](https://docs.screeps.com/api/#Flag.setPosition)
     #[wasm_bindgen(method, js_name = setPosition)]
     pub fn set_position(this: &Flag, pos: RoomPosition);
+
+    type Memory;
+
+    #[wasm_bindgen(static_method_of = Memory, getter = flags)]
+    fn flags() -> Option<Object>;
+}
+
+impl Flag {
+    /// The flag's position as a [`Position`], the same as
+    /// [`HasPosition::pos`].
+    pub fn local_pos(&self) -> Position {
+        self.pos()
+    }
+
+    /// Deserialize the flag's memory, `Memory.flags[flag.name]`, into `T`.
+    ///
+    /// Returns `None` if the flag has no memory, or it can't be deserialized
+    /// as `T`. The memory is read from `Memory.flags` directly rather than
+    /// through [`Flag::memory`], which sets missing memory to an empty object.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Flag.memory)
+    pub fn memory_typed<T: DeserializeOwned>(&self) -> Option<T> {
+        let flags = Memory::flags()?;
+        let memory = Reflect::get(flags.as_ref(), &self.name_jsstring()).ok()?;
+        memory_from(serde_wasm_bindgen::Deserializer::from(memory))
+    }
+
+    /// Serialize `value` into the flag's memory, `Memory.flags[flag.name]`,
+    /// replacing its current contents.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Flag.memory)
+    pub fn set_memory_typed<T: Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<(), serde_wasm_bindgen::Error> {
+        let value = serde_wasm_bindgen::to_value(value)?;
+        self.set_memory(&value);
+        Ok(())
+    }
+}
+
+/// Deserializes flag memory, treating missing memory as `None`.
+fn memory_from<'de, T, D>(deserializer: D) -> Option<T>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).ok().flatten()
 }
 
 impl JsCollectionFromValue for Flag {
@@ -74,3 +130,35 @@ impl JsCollectionFromValue for Flag {
         val.unchecked_into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::local::RoomName;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ClaimTask {
+        room: RoomName,
+        priority: u8,
+        reserve_only: bool,
+    }
+
+    #[test]
+    fn memory_round_trips_struct() {
+        let task = ClaimTask {
+            room: "W8N3".parse().unwrap(),
+            priority: 2,
+            reserve_only: false,
+        };
+        let memory = serde_json::to_value(&task).unwrap();
+        assert_eq!(memory_from::<ClaimTask, _>(memory), Some(task));
+    }
+
+    #[test]
+    fn empty_or_mismatched_memory_is_none() {
+        assert_eq!(memory_from::<ClaimTask, _>(Value::Null), None);
+        assert_eq!(memory_from::<ClaimTask, _>(json!({ "priority": 1 })), None);
+    }
+}
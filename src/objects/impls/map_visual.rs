@@ -445,4 +445,22 @@ impl MapVisual {
     pub fn text(pos: Position, text: String, style: MapTextStyle) {
         Self::draw(&MapVisualShape::text(pos, text, style));
     }
+
+    /// Remove all visuals drawn to the map so far in the current tick.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Game.map-visual.clear)
+    pub fn clear() {
+        crate::console::clear_visual(Some(&JsString::from("map")));
+    }
+
+    /// Get the size, in UTF-16 units, of the visuals drawn to the map so far in
+    /// the current tick. Visuals beyond [`MAP_VISUAL_SIZE_LIMIT`] are not
+    /// displayed.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Game.map-visual.getSize)
+    ///
+    /// [`MAP_VISUAL_SIZE_LIMIT`]: crate::constants::MAP_VISUAL_SIZE_LIMIT
+    pub fn get_size() -> u32 {
+        crate::console::get_visual_size(Some(&JsString::from("map")))
+    }
 }
@@ -7,7 +7,7 @@ use crate::{
     constants::{Direction, ErrorCode, PowerCreepClass, PowerType, ResourceType},
     local::RoomName,
     objects::{
-        CostMatrix, MoveToOptions, Owner, Resource, RoomObject, RoomPosition, Store,
+        truncate_say, CostMatrix, MoveToOptions, Owner, Resource, RoomObject, RoomPosition, Store,
         StructureController, StructurePowerSpawn,
     },
     pathfinder::SingleRoomCostResult,
@@ -298,11 +298,11 @@ impl PowerCreep {
     }
 
     /// Display a string in a bubble above the power creep next tick. 10
-    /// character limit.
+    /// character limit; longer messages are shortened with [`truncate_say`].
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#PowerCreep.say)
     pub fn say(&self, message: &str, public: bool) -> Result<(), ErrorCode> {
-        ErrorCode::result_from_i8(self.say_internal(message, public))
+        ErrorCode::result_from_i8(self.say_internal(truncate_say(message), public))
     }
 
     /// Immediately kill the power creep.
@@ -436,7 +436,7 @@ impl SharedCreepProperties for PowerCreep {
     }
 
     fn say(&self, message: &str, public: bool) -> Result<(), ErrorCode> {
-        ErrorCode::result_from_i8(self.say_internal(message, public))
+        ErrorCode::result_from_i8(self.say_internal(truncate_say(message), public))
     }
 
     fn suicide(&self) -> Result<(), ErrorCode> {
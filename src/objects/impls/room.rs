@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{collections::HashMap, fmt, hash::Hash};
 
 use js_sys::{Array, JsString, Object};
 use num_traits::*;
@@ -11,10 +11,11 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     constants::{
-        find::*, look::*, Color, Direction, ErrorCode, ExitDirection, PowerType, ResourceType,
-        StructureType,
+        find::*, look::*, Color, Direction, ErrorCode, ExitDirection, Part, PowerType,
+        ResourceType, StructureType, MAX_CREEP_SIZE,
     },
-    local::{LodashFilter, RoomName},
+    enums::StructureObject,
+    local::{LodashFilter, Position, RawObjectId, RoomName, RoomXY, Stamp},
     objects::*,
     pathfinder::RoomCostResult,
     prelude::*,
@@ -179,6 +180,61 @@ impl Room {
             .expect("expected parseable room name")
     }
 
+    /// Whether this room's controller is owned by you, `false` for rooms with
+    /// no controller.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#StructureController.my)
+    pub fn is_mine(&self) -> bool {
+        self.controller().is_some_and(|controller| controller.my())
+    }
+
+    /// Whether this room has a controller, and so can be claimed or reserved
+    /// when it isn't owned or reserved by another player; `false` for
+    /// highway, center and source keeper rooms.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.controller)
+    pub fn is_claimable(&self) -> bool {
+        self.controller().is_some()
+    }
+
+    /// The name of the player that has reserved this room's controller, or
+    /// `None` if it isn't reserved or the room has no controller.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#StructureController.reservation)
+    pub fn reserved_by(&self) -> Option<String> {
+        self.controller()?
+            .reservation()
+            .map(|reservation| reservation.username())
+    }
+
+    /// Gather a summary of the room's owner and defenses, which can be stored
+    /// to remember the room once it's out of vision.
+    pub fn intel_snapshot(&self) -> RoomIntel {
+        let controller = self.controller();
+        let tower_count = self
+            .find(crate::constants::find::STRUCTURES, None)
+            .iter()
+            .filter(|structure| matches!(structure, StructureObject::StructureTower(_)))
+            .count() as u32;
+        let mineral = self
+            .find(crate::constants::find::MINERALS, None)
+            .first()
+            .map(|mineral| mineral.mineral_type());
+
+        RoomIntel {
+            room_name: self.name(),
+            tick: crate::game::time(),
+            owner: controller
+                .as_ref()
+                .and_then(|controller| controller.owner())
+                .map(|owner| owner.username()),
+            controller_level: controller.map(|controller| controller.level()),
+            tower_count,
+            hostile_count: self.find(HOSTILE_CREEPS, None).len() as u32,
+            mineral,
+        }
+    }
+
     /// Serialize a path array from [`Room::find_path`] into a string
     /// representation safe to store in memory.
     ///
@@ -229,10 +285,55 @@ impl Room {
         ErrorCode::result_from_i8(self.create_construction_site_internal(x, y, ty, name))
     }
 
+    /// Creates construction sites at each of the given coordinates within this
+    /// room, returning the result of each placement in the same order.
+    ///
+    /// Once a placement fails with [`ErrorCode::Full`] (the player already has
+    /// [`MAX_CONSTRUCTION_SITES`] sites), no further placements are attempted
+    /// and the remaining entries are filled with that error.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.createConstructionSite)
+    ///
+    /// [`MAX_CONSTRUCTION_SITES`]: crate::constants::MAX_CONSTRUCTION_SITES
+    pub fn create_construction_sites(
+        &self,
+        placements: &[(u8, u8, StructureType)],
+    ) -> Vec<Result<(), ErrorCode>> {
+        place_until_full(placements, |x, y, ty| {
+            self.create_construction_site(x, y, ty, None)
+        })
+    }
+
+    /// Creates construction sites for every structure in a [`Stamp`], with
+    /// the stamp's anchor cell placed at `anchor`, returning the result of
+    /// each placement in the stamp's row order.
+    ///
+    /// Structures falling outside of the room, or every structure if `anchor`
+    /// is in another room, get [`ErrorCode::InvalidArgs`] without a call into
+    /// the game. As with [`Room::create_construction_sites`], once a placement
+    /// fails with [`ErrorCode::Full`] the rest are filled with that error.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.createConstructionSite)
+    pub fn place_stamp(&self, stamp: &Stamp, anchor: Position) -> Vec<Result<(), ErrorCode>> {
+        let same_room = anchor.room_name() == self.name();
+        place_each_until_full(&stamp.placements(anchor.xy()), |(xy, ty)| match xy {
+            Some(xy) if same_room => self.create_construction_site(xy.x.u8(), xy.y.u8(), ty, None),
+            _ => Err(ErrorCode::InvalidArgs),
+        })
+    }
+
     /// Creates a [`Flag`] at given coordinates within this room. The name of
     /// the flag is returned if the creation is successful.
     ///
+    /// Returns [`ErrorCode::Full`] if you already have [`FLAGS_LIMIT`] flags,
+    /// [`ErrorCode::NameExists`] if a flag with the given name already exists,
+    /// or [`ErrorCode::InvalidArgs`] if the name is longer than
+    /// [`FLAG_NAME_MAX_LENGTH`] or a color is invalid.
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Room.createFlag)
+    ///
+    /// [`FLAGS_LIMIT`]: crate::constants::FLAGS_LIMIT
+    /// [`FLAG_NAME_MAX_LENGTH`]: crate::constants::FLAG_NAME_MAX_LENGTH
     pub fn create_flag(
         &self,
         x: u8,
@@ -271,6 +372,184 @@ impl Room {
             .collect()
     }
 
+    /// Find your [`StructureSpawn`]s and [`StructureExtension`]s in the room
+    /// which have free capacity for energy, sorted by range from `from`,
+    /// closest first.
+    pub fn extensions_and_spawns_needing_energy(&self, from: Position) -> Vec<StructureObject> {
+        let candidates = self
+            .find(MY_STRUCTURES, None)
+            .into_iter()
+            .filter_map(|structure| {
+                let store = match &structure {
+                    StructureObject::StructureSpawn(spawn) => spawn.store(),
+                    StructureObject::StructureExtension(extension) => extension.store(),
+                    _ => return None,
+                };
+                let free = store.get_free_capacity(Some(ResourceType::Energy));
+                let pos = structure.pos();
+                Some((structure, pos, free))
+            });
+
+        not_full_by_range(candidates, from)
+    }
+
+    /// Get the energy available for spawning and the total energy capacity of
+    /// your [`StructureSpawn`]s and [`StructureExtension`]s in the room, as
+    /// `(available, capacity)`.
+    ///
+    /// This is summed from the structures themselves, rather than read from
+    /// [`Room::energy_available`] and [`Room::energy_capacity_available`].
+    pub fn spawn_energy(&self) -> (u32, u32) {
+        self.find(MY_STRUCTURES, None)
+            .into_iter()
+            .filter_map(|structure| match structure {
+                StructureObject::StructureSpawn(spawn) => Some(spawn.store()),
+                StructureObject::StructureExtension(extension) => Some(extension.store()),
+                _ => None,
+            })
+            .fold((0, 0), |(available, capacity), store| {
+                (
+                    available + store.get_used_capacity(Some(ResourceType::Energy)),
+                    capacity + store.get_capacity(Some(ResourceType::Energy)).unwrap_or(0),
+                )
+            })
+    }
+
+    /// Build the largest body which can be spawned with the energy currently
+    /// available in the room, by repeating `template` until either the next
+    /// part can't be afforded or the body reaches [`MAX_CREEP_SIZE`] parts.
+    ///
+    /// The final repetition of the template may be cut short, keeping the
+    /// parts from its start. Returns an empty body if the first part of the
+    /// template can't be afforded, or the template is empty.
+    pub fn max_spawnable_body(&self, template: &[Part]) -> Vec<Part> {
+        fit_body(template, self.energy_available())
+    }
+
+    /// Find structures in the room with hits below `threshold` as a fraction
+    /// of their maximum hits, such as `0.75` for structures below 75%.
+    ///
+    /// Structures owned by other players are skipped, while unowned
+    /// structures like roads and containers are included. Walls and ramparts,
+    /// which have far higher maximum hits than other structures, are only
+    /// included if `include_walls_and_ramparts` is set.
+    pub fn structures_needing_repair(
+        &self,
+        threshold: f64,
+        include_walls_and_ramparts: bool,
+    ) -> Vec<StructureObject> {
+        self.find(crate::constants::find::STRUCTURES, None)
+            .into_iter()
+            .filter(|structure| {
+                if !include_walls_and_ramparts
+                    && matches!(
+                        structure.structure_type(),
+                        StructureType::Wall | StructureType::Rampart
+                    )
+                {
+                    return false;
+                }
+                if structure.as_owned().is_some_and(|owned| !owned.my()) {
+                    return false;
+                }
+                structure.as_repairable().is_some_and(|repairable| {
+                    below_hits_threshold(repairable.hits(), repairable.hits_max(), threshold)
+                })
+            })
+            .collect()
+    }
+
+    /// Find your other [`StructureLab`]s in the room within `range` of the
+    /// given lab.
+    pub fn labs_in_range(&self, lab: &StructureLab, range: u32) -> Vec<StructureLab> {
+        let pos = lab.pos();
+        self.my_labs()
+            .into_iter()
+            .filter(|other| {
+                let other_pos = other.pos();
+                other_pos != pos && pos.get_range_to(other_pos) <= range
+            })
+            .collect()
+    }
+
+    /// Find every combination of your [`StructureLab`]s in the room which can
+    /// run a reaction, as `(output, input_1, input_2)`, where both input labs
+    /// are within range 2 of the output lab.
+    ///
+    /// Each pair of input labs is only listed once for each output lab, in the
+    /// order they're found.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#StructureLab.runReaction)
+    pub fn reaction_labs(&self) -> Vec<(StructureLab, StructureLab, StructureLab)> {
+        let labs = self.my_labs();
+        let positions: Vec<Position> = labs.iter().map(|lab| lab.pos()).collect();
+        reaction_triples(&positions)
+            .into_iter()
+            .map(|(output, input_1, input_2)| {
+                (
+                    labs[output].clone(),
+                    labs[input_1].clone(),
+                    labs[input_2].clone(),
+                )
+            })
+            .collect()
+    }
+
+    fn my_labs(&self) -> Vec<StructureLab> {
+        self.find(MY_STRUCTURES, None)
+            .into_iter()
+            .filter_map(|structure| match structure {
+                StructureObject::StructureLab(lab) => Some(lab),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Find your [`ConstructionSite`] in the room which is closest to
+    /// completion, by the fraction of its total progress made so far, or
+    /// `None` if you have no construction sites in the room.
+    pub fn most_complete_construction_site(&self) -> Option<ConstructionSite> {
+        most_complete(self.find(MY_CONSTRUCTION_SITES, None), |site| {
+            (site.progress(), site.progress_total())
+        })
+    }
+
+    /// Suggest a single transfer of energy between the room's
+    /// [`StructureStorage`] and [`StructureTerminal`] which keeps
+    /// `keep_in_storage` energy in the storage, as `(from, to, amount)`.
+    ///
+    /// Energy above the reserve is moved to the terminal, and a shortfall is
+    /// made up from the terminal, each limited by the energy available and the
+    /// free capacity of the receiving structure. Returns `None` if the room
+    /// lacks either structure, or no energy needs to (or can) be moved.
+    ///
+    /// This only plans the transfer; carrying it out with a creep is left to
+    /// the caller.
+    pub fn balance_energy(
+        &self,
+        keep_in_storage: u32,
+    ) -> Option<(StructureObject, StructureObject, u32)> {
+        let storage = self.storage()?;
+        let terminal = self.terminal()?;
+
+        let storage_store = storage.store();
+        let terminal_store = terminal.store();
+        let transfer = energy_transfer(
+            keep_in_storage,
+            storage_store.get_used_capacity(Some(ResourceType::Energy)),
+            storage_store.get_free_capacity(Some(ResourceType::Energy)),
+            terminal_store.get_used_capacity(Some(ResourceType::Energy)),
+            terminal_store.get_free_capacity(Some(ResourceType::Energy)),
+        )?;
+
+        let storage = StructureObject::StructureStorage(storage);
+        let terminal = StructureObject::StructureTerminal(terminal);
+        Some(match transfer {
+            EnergyTransfer::ToTerminal(amount) => (storage, terminal, amount),
+            EnergyTransfer::ToStorage(amount) => (terminal, storage, amount),
+        })
+    }
+
     /// Find an exit from the current room which leads to a target room.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Room.findExitTo)
@@ -306,10 +585,54 @@ impl Room {
         }
     }
 
+    /// Find a path within the room from one position to another, returning the
+    /// positions along the path, not including `origin`.
+    ///
+    /// Any [`FindPathOptions::serialize`] setting is ignored, since the path
+    /// must be in its unserialized form to convert it.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.findPath)
+    pub fn find_path_positions<F, R>(
+        &self,
+        origin: &RoomPosition,
+        goal: &RoomPosition,
+        options: Option<FindPathOptions<F, R>>,
+    ) -> Vec<Position>
+    where
+        F: FnMut(RoomName, CostMatrix) -> R,
+        R: RoomCostResult,
+    {
+        match self.find_path(
+            origin,
+            goal,
+            options.map(|options| options.serialize(false)),
+        ) {
+            Path::Vectorized(steps) => steps_to_positions(self.name(), &steps),
+            Path::Serialized(_) => unreachable!("path serialization was disabled"),
+        }
+    }
+
     pub fn get_event_log(&self) -> Vec<Event> {
         serde_json::from_str(&self.get_event_log_raw()).expect("Malformed Event Log")
     }
 
+    /// Sum the damage dealt to each object in the room during the last tick,
+    /// from every [`EventType::Attack`] in the event log, including hit back
+    /// and nuke damage.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.getEventLog)
+    pub fn damage_taken_this_tick(&self) -> HashMap<RawObjectId, u32> {
+        damage_by_target(&self.get_event_log())
+    }
+
+    /// Get the events of a single type from the room's event log for the
+    /// last tick, such as only [`EventTypeDiscriminant::Attack`] events.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.getEventLog)
+    pub fn events_of_type(&self, ty: EventTypeDiscriminant) -> Vec<Event> {
+        filter_events(self.get_event_log(), ty)
+    }
+
     pub fn get_event_log_raw(&self) -> String {
         let js_log: JsString = Room::get_event_log_internal(self, true).into();
         js_log.into()
@@ -354,6 +677,31 @@ impl Room {
             .unwrap_or_default()
     }
 
+    /// Get all objects in a certain area, grouped by the `(x, y)` coordinates
+    /// of the tile they're on. Tiles with no results are omitted.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.lookAtArea)
+    pub fn look_at_area_grid(
+        &self,
+        top_y: u8,
+        left_x: u8,
+        bottom_y: u8,
+        right_x: u8,
+    ) -> HashMap<(u8, u8), Vec<LookResult>> {
+        group_by_tile(self.look_at_area(top_y, left_x, bottom_y, right_x))
+    }
+
+    /// Find all structures in the room, grouped by [`StructureType`]. Types
+    /// with no structures in the room are omitted.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.find)
+    pub fn structures_by_type(&self) -> HashMap<StructureType, Vec<StructureObject>> {
+        group_by(
+            self.find(crate::constants::find::STRUCTURES, None),
+            |structure| (structure.structure_type(), structure),
+        )
+    }
+
     /// Get all objects of a given type at this position, if any.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Room.lookForAt)
@@ -414,6 +762,214 @@ impl PartialEq for Room {
 
 impl Eq for Room {}
 
+fn group_by_tile(
+    results: impl IntoIterator<Item = PositionedLookResult>,
+) -> HashMap<(u8, u8), Vec<LookResult>> {
+    group_by(results, |result| ((result.x, result.y), result.look_result))
+}
+
+/// Groups values by key, keeping the order values were given in within each
+/// group.
+fn group_by<T, K, V>(
+    items: impl IntoIterator<Item = T>,
+    key_value: impl Fn(T) -> (K, V),
+) -> HashMap<K, Vec<V>>
+where
+    K: Eq + Hash,
+{
+    let mut groups: HashMap<K, Vec<V>> = HashMap::new();
+    for item in items {
+        let (key, value) = key_value(item);
+        groups.entry(key).or_default().push(value);
+    }
+    groups
+}
+
+/// Keeps the items with free capacity, sorted by range from `from`, closest
+/// first.
+fn not_full_by_range<T>(
+    candidates: impl IntoIterator<Item = (T, Position, i32)>,
+    from: Position,
+) -> Vec<T> {
+    let mut not_full: Vec<(T, u32)> = candidates
+        .into_iter()
+        .filter(|(_, _, free)| *free > 0)
+        .map(|(item, pos, _)| (item, from.get_range_to(pos)))
+        .collect();
+    not_full.sort_by_key(|(_, range)| *range);
+    not_full.into_iter().map(|(item, _)| item).collect()
+}
+
+/// Runs `place` for each placement in order, stopping once the construction
+/// site limit is hit and filling the rest of the results with that error.
+fn place_until_full(
+    placements: &[(u8, u8, StructureType)],
+    mut place: impl FnMut(u8, u8, StructureType) -> Result<(), ErrorCode>,
+) -> Vec<Result<(), ErrorCode>> {
+    place_each_until_full(placements, |(x, y, ty)| place(x, y, ty))
+}
+
+/// As [`place_until_full`], for placements of any form.
+fn place_each_until_full<T: Copy>(
+    placements: &[T],
+    mut place: impl FnMut(T) -> Result<(), ErrorCode>,
+) -> Vec<Result<(), ErrorCode>> {
+    let mut results = Vec::with_capacity(placements.len());
+    for &placement in placements {
+        let result = place(placement);
+        results.push(result);
+        if result == Err(ErrorCode::Full) {
+            break;
+        }
+    }
+    results.resize(placements.len(), Err(ErrorCode::Full));
+    results
+}
+
+/// Finds the item with the highest ratio of progress to total progress, keeping
+/// the first of any items with equal ratios.
+fn most_complete<T>(
+    items: impl IntoIterator<Item = T>,
+    progress: impl Fn(&T) -> (u32, u32),
+) -> Option<T> {
+    let mut best: Option<(T, u32, u32)> = None;
+    for item in items {
+        let (done, total) = progress(&item);
+        let better = match &best {
+            // compare done / total > best_done / best_total without dividing
+            Some((_, best_done, best_total)) => {
+                done as u64 * *best_total as u64 > *best_done as u64 * total as u64
+            }
+            None => true,
+        };
+        if better {
+            best = Some((item, done, total));
+        }
+    }
+    best.map(|(item, _, _)| item)
+}
+
+fn filter_events(events: Vec<Event>, ty: EventTypeDiscriminant) -> Vec<Event> {
+    events
+        .into_iter()
+        .filter(|event| event.event.discriminant() == ty)
+        .collect()
+}
+
+fn damage_by_target(events: &[Event]) -> HashMap<RawObjectId, u32> {
+    let mut damage = HashMap::new();
+    for event in events {
+        if let EventType::Attack(attack) = &event.event {
+            if let Ok(target) = attack.target_id.parse() {
+                *damage.entry(target).or_insert(0) += attack.damage;
+            }
+        }
+    }
+    damage
+}
+
+/// Finds the indices of every output position with two distinct input
+/// positions in reaction range, keeping the inputs in their original order.
+fn reaction_triples(positions: &[Position]) -> Vec<(usize, usize, usize)> {
+    // `StructureLab.runReaction` requires both input labs in range 2
+    let in_range = |a: usize, b: usize| a != b && positions[a].get_range_to(positions[b]) <= 2;
+
+    let mut triples = Vec::new();
+    for output in 0..positions.len() {
+        for input_1 in 0..positions.len() {
+            if !in_range(output, input_1) {
+                continue;
+            }
+            for input_2 in input_1 + 1..positions.len() {
+                if in_range(output, input_2) {
+                    triples.push((output, input_1, input_2));
+                }
+            }
+        }
+    }
+    triples
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum EnergyTransfer {
+    ToTerminal(u32),
+    ToStorage(u32),
+}
+
+/// Plans moving energy between a storage and terminal to bring the storage to
+/// `keep_in_storage`, limited by what the sender has and the receiver can fit.
+fn energy_transfer(
+    keep_in_storage: u32,
+    storage_energy: u32,
+    storage_free: i32,
+    terminal_energy: u32,
+    terminal_free: i32,
+) -> Option<EnergyTransfer> {
+    let storage_free = storage_free.max(0) as u32;
+    let terminal_free = terminal_free.max(0) as u32;
+
+    let transfer = if storage_energy > keep_in_storage {
+        EnergyTransfer::ToTerminal((storage_energy - keep_in_storage).min(terminal_free))
+    } else {
+        EnergyTransfer::ToStorage(
+            (keep_in_storage - storage_energy)
+                .min(terminal_energy)
+                .min(storage_free),
+        )
+    };
+
+    match transfer {
+        EnergyTransfer::ToTerminal(0) | EnergyTransfer::ToStorage(0) => None,
+        transfer => Some(transfer),
+    }
+}
+
+/// Serializes a path in the same form as `Room.serializePath`: the first
+/// step's coordinates as two zero-padded numbers, then each step's direction
+/// as a digit.
+pub(crate) fn serialize_steps(steps: &[Step]) -> String {
+    let first = match steps.first() {
+        Some(first) => first,
+        None => return String::new(),
+    };
+
+    let mut serialized = format!("{:02}{:02}", first.x, first.y);
+    serialized.extend(
+        steps
+            .iter()
+            .map(|step| char::from(b'0' + step.direction as u8)),
+    );
+    serialized
+}
+
+fn fit_body(template: &[Part], energy: u32) -> Vec<Part> {
+    let mut body = Vec::new();
+    let mut cost = 0;
+    for &part in template.iter().cycle().take(MAX_CREEP_SIZE as usize) {
+        cost += part.cost();
+        if cost > energy {
+            break;
+        }
+        body.push(part);
+    }
+    body
+}
+
+fn below_hits_threshold(hits: u32, hits_max: u32, threshold: f64) -> bool {
+    hits_max > 0 && (hits as f64) < threshold * hits_max as f64
+}
+
+fn steps_to_positions(room_name: RoomName, steps: &[Step]) -> Vec<Position> {
+    steps
+        .iter()
+        .map(|step| {
+            let xy = RoomXY::checked_new(step.x as u8, step.y as u8)
+                .expect("expected path step to be within the room");
+            Position::new(xy.x, xy.y, room_name)
+        })
+        .collect()
+}
+
 impl JsCollectionFromValue for Room {
     fn from_value(val: JsValue) -> Self {
         val.unchecked_into()
@@ -422,6 +978,7 @@ impl JsCollectionFromValue for Room {
 
 #[wasm_bindgen]
 extern "C" {
+    /// Options for [`Room::find`].
     #[wasm_bindgen]
     pub type FindOptions;
 
@@ -429,6 +986,90 @@ extern "C" {
     pub fn filter(this: &FindOptions, filter: LodashFilter);
 }
 
+impl FindOptions {
+    pub fn new() -> FindOptions {
+        Object::new().unchecked_into()
+    }
+
+    /// Create options which only find objects matching a [`FindFilter`].
+    ///
+    /// The filter is applied in JavaScript, so objects which don't match are
+    /// never converted into Rust types.
+    pub fn with_filter(filter: &FindFilter) -> FindOptions {
+        let options = FindOptions::new();
+        let filter = serde_wasm_bindgen::to_value(filter).expect("expected to serialize filter");
+        options.filter(filter.unchecked_into());
+        options
+    }
+}
+
+impl Default for FindOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A filter on properties of found objects, for use with
+/// [`FindOptions::with_filter`].
+///
+/// Objects match if every property that's been set is equal; this is passed
+/// to the game as an object for lodash's `_.matches`.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::{constants::find, game, FindFilter, FindOptions, StructureType};
+///
+/// let room = game::rooms().get("W1N1".parse().unwrap()).unwrap();
+/// let filter = FindFilter::new().structure_type(StructureType::Tower);
+/// let towers = room.find(
+///     find::MY_STRUCTURES,
+///     Some(&FindOptions::with_filter(&filter)),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    structure_type: Option<StructureType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    my: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<FindFilterOwner>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct FindFilterOwner {
+    username: String,
+}
+
+impl FindFilter {
+    /// Create a filter which matches all objects.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match structures of the given type.
+    pub fn structure_type(mut self, structure_type: StructureType) -> Self {
+        self.structure_type = Some(structure_type);
+        self
+    }
+
+    /// Only match objects which are, or aren't, owned by you.
+    pub fn my(mut self, my: bool) -> Self {
+        self.my = Some(my);
+        self
+    }
+
+    /// Only match objects owned by the player with the given username.
+    pub fn owner(mut self, username: &str) -> Self {
+        self.owner = Some(FindFilterOwner {
+            username: username.to_owned(),
+        });
+        self
+    }
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen]
@@ -546,6 +1187,18 @@ where
 
     /// Sets cost callback - default `|_, _| {}`.
     pub fn cost_callback<F2, R2>(self, cost_callback: F2) -> FindPathOptions<F2, R2>
+    where
+        F2: FnMut(RoomName, CostMatrix) -> R2,
+        R2: RoomCostResult,
+    {
+        self.map_cost_callback(|_| cost_callback)
+    }
+
+    /// Replaces the cost callback with one built from the current callback.
+    pub(crate) fn map_cost_callback<F2, R2>(
+        self,
+        f: impl FnOnce(F) -> F2,
+    ) -> FindPathOptions<F2, R2>
     where
         F2: FnMut(RoomName, CostMatrix) -> R2,
         R2: RoomCostResult,
@@ -553,6 +1206,7 @@ where
         let FindPathOptions {
             ignore_creeps,
             ignore_destructible_structures,
+            cost_callback,
             max_ops,
             heuristic_weight,
             serialize,
@@ -560,13 +1214,12 @@ where
             range,
             plain_cost,
             swamp_cost,
-            ..
         } = self;
 
         FindPathOptions {
             ignore_creeps,
             ignore_destructible_structures,
-            cost_callback,
+            cost_callback: f(cost_callback),
             max_ops,
             heuristic_weight,
             serialize,
@@ -618,7 +1271,34 @@ where
         self
     }
 
+    /// The options other than the cost callback, as they're set on the JS
+    /// options object.
+    pub(crate) fn values(&self) -> FindPathValues {
+        FindPathValues {
+            ignore_creeps: self.ignore_creeps,
+            ignore_destructible_structures: self.ignore_destructible_structures,
+            max_ops: self.max_ops,
+            heuristic_weight: self.heuristic_weight,
+            serialize: self.serialize,
+            max_rooms: self.max_rooms,
+            range: self.range,
+            plain_cost: self.plain_cost,
+            swamp_cost: self.swamp_cost,
+        }
+    }
+
     pub(crate) fn into_js_options<CR>(self, callback: impl Fn(&JsFindPathOptions) -> CR) -> CR {
+        let values = self.values();
+        self.into_js_options_with(&values, callback)
+    }
+
+    /// Creates the JS options object from `values`, which should include
+    /// these options' [`FindPathValues`], then sets the cost callback on it.
+    pub(crate) fn into_js_options_with<CR>(
+        self,
+        values: &impl Serialize,
+        callback: impl Fn(&JsFindPathOptions) -> CR,
+    ) -> CR {
         let mut raw_callback = self.cost_callback;
 
         let mut owned_callback = move |room: RoomName, cost_matrix: CostMatrix| -> JsValue {
@@ -630,7 +1310,7 @@ where
         // passed in, now unified as &Fn
         //
 
-        let callback_type_erased: &mut (dyn FnMut(RoomName, CostMatrix) -> JsValue) =
+        let callback_type_erased: &mut dyn FnMut(RoomName, CostMatrix) -> JsValue =
             &mut owned_callback;
 
         // Overwrite lifetime of reference so it can be passed to javascript.
@@ -640,7 +1320,7 @@ where
         // above the current scope but otherwise unknown" is not a valid lifetime.
         //
 
-        let callback_lifetime_erased: &'static mut (dyn FnMut(RoomName, CostMatrix) -> JsValue) =
+        let callback_lifetime_erased: &'static mut dyn FnMut(RoomName, CostMatrix) -> JsValue =
             unsafe { std::mem::transmute(callback_type_erased) };
 
         let boxed_callback = Box::new(move |room: JsString, cost_matrix: CostMatrix| -> JsValue {
@@ -657,50 +1337,63 @@ where
         // Create JS object and set properties.
         //
 
-        let js_options = JsFindPathOptions::new();
+        let js_options: JsFindPathOptions = values
+            .serialize(&serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true))
+            .expect("expected to serialize path finding options")
+            .unchecked_into();
 
         js_options.cost_callback(&closure);
 
-        if let Some(ignore_creeps) = self.ignore_creeps {
-            js_options.ignore_creeps(ignore_creeps);
-        }
-
-        if let Some(ignore_destructible_structures) = self.ignore_destructible_structures {
-            js_options.ignore_destructible_structures(ignore_destructible_structures);
-        }
-
-        if let Some(max_ops) = self.max_ops {
-            js_options.max_ops(max_ops);
-        }
-
-        if let Some(heuristic_weight) = self.heuristic_weight {
-            js_options.heuristic_weight(heuristic_weight);
-        }
-
-        if let Some(serialize) = self.serialize {
-            js_options.serialize(serialize);
-        }
-
-        if let Some(max_rooms) = self.max_rooms {
-            js_options.max_rooms(max_rooms);
-        }
-
-        if let Some(range) = self.range {
-            js_options.range(range);
-        }
-
-        if let Some(plain_cost) = self.plain_cost {
-            js_options.plain_cost(plain_cost);
-        }
-
-        if let Some(swamp_cost) = self.swamp_cost {
-            js_options.swamp_cost(swamp_cost);
-        }
-
         callback(&js_options)
     }
 }
 
+/// The options of a [`FindPathOptions`] other than the cost callback, with
+/// unset options left out so the game's defaults apply.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FindPathValues {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_creeps: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_destructible_structures: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_ops: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    heuristic_weight: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    serialize: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_rooms: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plain_cost: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    swamp_cost: Option<u8>,
+}
+
+/// A summary of a room, gathered by [`Room::intel_snapshot`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomIntel {
+    /// The name of the room.
+    pub room_name: RoomName,
+    /// The game tick the snapshot was taken on.
+    pub tick: u32,
+    /// The name of the player who owns the room's controller, if any.
+    pub owner: Option<String>,
+    /// The level of the room's controller, or `None` if the room has no
+    /// controller; 0 for unowned controllers.
+    pub controller_level: Option<u8>,
+    /// The number of towers in the room, regardless of owner.
+    pub tower_count: u32,
+    /// The number of creeps in the room which aren't yours.
+    pub hostile_count: u32,
+    /// The type of the room's mineral, if it has one.
+    pub mineral: Option<ResourceType>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Step {
     pub x: u32,
@@ -854,6 +1547,66 @@ pub enum EventType {
     Transfer(TransferEvent),
 }
 
+/// The kind of an [`EventType`], without its data, matching the numeric
+/// `EVENT_*` constants.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Room.getEventLog)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum EventTypeDiscriminant {
+    Attack = 1,
+    ObjectDestroyed = 2,
+    AttackController = 3,
+    Build = 4,
+    Harvest = 5,
+    Heal = 6,
+    Repair = 7,
+    ReserveController = 8,
+    UpgradeController = 9,
+    Exit = 10,
+    Power = 11,
+    Transfer = 12,
+}
+
+impl EventType {
+    /// The kind of this event, without its data.
+    pub fn discriminant(&self) -> EventTypeDiscriminant {
+        match self {
+            EventType::Attack(_) => EventTypeDiscriminant::Attack,
+            EventType::ObjectDestroyed(_) => EventTypeDiscriminant::ObjectDestroyed,
+            EventType::AttackController => EventTypeDiscriminant::AttackController,
+            EventType::Build(_) => EventTypeDiscriminant::Build,
+            EventType::Harvest(_) => EventTypeDiscriminant::Harvest,
+            EventType::Heal(_) => EventTypeDiscriminant::Heal,
+            EventType::Repair(_) => EventTypeDiscriminant::Repair,
+            EventType::ReserveController(_) => EventTypeDiscriminant::ReserveController,
+            EventType::UpgradeController(_) => EventTypeDiscriminant::UpgradeController,
+            EventType::Exit(_) => EventTypeDiscriminant::Exit,
+            EventType::Power(_) => EventTypeDiscriminant::Power,
+            EventType::Transfer(_) => EventTypeDiscriminant::Transfer,
+        }
+    }
+
+    /// The ID of the object targeted by this event, for event types which have
+    /// a target.
+    pub fn target_id(&self) -> Option<&str> {
+        match self {
+            EventType::Attack(e) => Some(&e.target_id),
+            EventType::Build(e) => Some(&e.target_id),
+            EventType::Harvest(e) => Some(&e.target_id),
+            EventType::Heal(e) => Some(&e.target_id),
+            EventType::Repair(e) => Some(&e.target_id),
+            EventType::Power(e) => Some(&e.target_id),
+            EventType::Transfer(e) => Some(&e.target_id),
+            EventType::ObjectDestroyed(_)
+            | EventType::AttackController
+            | EventType::ReserveController(_)
+            | EventType::UpgradeController(_)
+            | EventType::Exit(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AttackEvent {
@@ -959,3 +1712,473 @@ pub struct PowerEvent {
     pub target_id: String,
     pub power: PowerType,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{constants::Terrain, local::RoomCoordinate};
+
+    fn pos(x: u8, y: u8) -> Position {
+        let xy = RoomXY::checked_new(x, y).unwrap();
+        Position::new(xy.x, xy.y, "W5N5".parse::<RoomName>().unwrap())
+    }
+
+    #[test]
+    fn not_full_by_range_skips_full_structures() {
+        let from = pos(25, 25);
+
+        let candidates = [
+            ("far extension", pos(35, 25), 50),
+            ("full extension", pos(26, 25), 0),
+            ("spawn", pos(27, 27), 100),
+            ("near extension", pos(25, 24), 20),
+            ("overfilled extension", pos(24, 24), -10),
+        ];
+        assert_eq!(
+            not_full_by_range(candidates, from),
+            ["near extension", "spawn", "far extension"]
+        );
+
+        let all_full = [("spawn", pos(27, 27), 0), ("extension", pos(26, 25), 0)];
+        assert!(not_full_by_range(all_full, from).is_empty());
+    }
+
+    #[test]
+    fn fit_body_truncates_to_energy() {
+        use Part::*;
+
+        let template = [Work, Carry, Move];
+        assert_eq!(fit_body(&template, 200), [Work, Carry, Move]);
+        assert_eq!(fit_body(&template, 299), [Work, Carry, Move]);
+        assert_eq!(fit_body(&template, 300), [Work, Carry, Move, Work]);
+        assert_eq!(
+            fit_body(&template, 449),
+            [Work, Carry, Move, Work, Carry, Move]
+        );
+        assert_eq!(fit_body(&template, 99), []);
+        assert_eq!(fit_body(&[], 1000), []);
+    }
+
+    #[test]
+    fn fit_body_caps_at_max_creep_size() {
+        let body = fit_body(&[Part::Move], 100_000);
+        assert_eq!(body.len(), MAX_CREEP_SIZE as usize);
+    }
+
+    #[test]
+    fn hits_threshold_filtering() {
+        assert!(below_hits_threshold(3999, 5000, 0.8));
+        assert!(!below_hits_threshold(4000, 5000, 0.8));
+        assert!(!below_hits_threshold(5000, 5000, 0.8));
+        assert!(below_hits_threshold(4999, 5000, 1.0));
+        assert!(!below_hits_threshold(5000, 5000, 1.0));
+        assert!(!below_hits_threshold(1, 5000, 0.0));
+        // indestructible structures report no maximum hits
+        assert!(!below_hits_threshold(0, 0, 0.5));
+    }
+
+    #[test]
+    fn find_filter_serializes_to_matches_object() {
+        assert_eq!(
+            serde_json::to_value(FindFilter::new()).unwrap(),
+            serde_json::json!({})
+        );
+        assert_eq!(
+            serde_json::to_value(FindFilter::new().structure_type(StructureType::Tower)).unwrap(),
+            serde_json::json!({ "structureType": "tower" })
+        );
+        assert_eq!(
+            serde_json::to_value(FindFilter::new().my(false).owner("Invader")).unwrap(),
+            serde_json::json!({ "my": false, "owner": { "username": "Invader" } })
+        );
+    }
+
+    #[test]
+    fn reaction_triples_by_range() {
+        // (10, 10) and (12, 12) are 2 apart, (10, 12) within range of both,
+        // and (20, 20) out of range of all of them
+        let positions = [pos(10, 10), pos(12, 12), pos(10, 12), pos(20, 20)];
+        assert_eq!(
+            reaction_triples(&positions),
+            vec![(0, 1, 2), (1, 0, 2), (2, 0, 1)]
+        );
+
+        // a single pair can't react without a second input
+        assert_eq!(reaction_triples(&positions[..2]), vec![]);
+        assert_eq!(
+            reaction_triples(&[pos(10, 10), pos(13, 10), pos(7, 10)]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn energy_transfer_keeps_reserve() {
+        // over-reserved storage sends the excess to the terminal
+        assert_eq!(
+            energy_transfer(100_000, 150_000, 850_000, 20_000, 280_000),
+            Some(EnergyTransfer::ToTerminal(50_000))
+        );
+        // limited by the terminal's free capacity
+        assert_eq!(
+            energy_transfer(100_000, 150_000, 850_000, 20_000, 10_000),
+            Some(EnergyTransfer::ToTerminal(10_000))
+        );
+        // under-reserved storage takes what the terminal has
+        assert_eq!(
+            energy_transfer(100_000, 70_000, 930_000, 50_000, 250_000),
+            Some(EnergyTransfer::ToStorage(30_000))
+        );
+        assert_eq!(
+            energy_transfer(100_000, 70_000, 930_000, 12_000, 288_000),
+            Some(EnergyTransfer::ToStorage(12_000))
+        );
+        // balanced, or nothing can move
+        assert_eq!(
+            energy_transfer(100_000, 100_000, 900_000, 5_000, 295_000),
+            None
+        );
+        assert_eq!(energy_transfer(100_000, 150_000, 850_000, 300_000, 0), None);
+        assert_eq!(energy_transfer(100_000, 70_000, 930_000, 0, 300_000), None);
+        assert_eq!(energy_transfer(100_000, 70_000, 0, 50_000, 250_000), None);
+    }
+
+    #[test]
+    fn serialized_path_replays_local_path() {
+        let origin = pos(3, 8);
+        let mut costs = crate::local::LocalCostMatrix::new_with_value(1);
+        for y in 0..=10 {
+            costs.set(RoomXY::checked_new(5, y).unwrap(), 255);
+        }
+
+        let path = crate::local::find_path_local(
+            origin,
+            pos(7, 8),
+            &costs,
+            crate::local::LocalPathOptions::default(),
+        )
+        .unwrap();
+
+        // the steps `Room.findPath` would give for the same path
+        let mut previous = origin;
+        let steps: Vec<Step> = path
+            .iter()
+            .map(|pos| {
+                let (dx, dy) = *pos - previous;
+                let step = Step {
+                    x: pos.x().u8() as u32,
+                    y: pos.y().u8() as u32,
+                    dx,
+                    dy,
+                    direction: previous.get_direction_to(*pos).unwrap(),
+                };
+                previous = *pos;
+                step
+            })
+            .collect();
+
+        let serialized = serialize_steps(&steps);
+        assert!(serialized.starts_with(&format!("{:02}{:02}", steps[0].x, steps[0].y)));
+        assert_eq!(serialized.len(), 4 + path.len());
+
+        // replay it as `Room.deserializePath` and `Creep.moveByPath` do: the
+        // first step is at the encoded position, each later one is offset by
+        // its direction
+        let mut replayed = Vec::new();
+        let mut current = pos(
+            serialized[0..2].parse().unwrap(),
+            serialized[2..4].parse().unwrap(),
+        );
+        for (i, digit) in serialized[4..].chars().enumerate() {
+            let direction = Direction::from_u8(digit.to_digit(10).unwrap() as u8).unwrap();
+            if i > 0 {
+                current = current.checked_add_direction(direction).unwrap();
+            }
+            replayed.push(current);
+        }
+        assert_eq!(replayed, path);
+
+        assert_eq!(serialize_steps(&[]), "");
+    }
+
+    #[test]
+    fn steps_to_positions_follows_path() {
+        let room_name: RoomName = "W5N5".parse().unwrap();
+        let origin = Position::new(
+            RoomCoordinate::new(10).unwrap(),
+            RoomCoordinate::new(10).unwrap(),
+            room_name,
+        );
+        // the steps `Room.findPath` returns from (10, 10) to (12, 13)
+        let steps = [
+            (11, 11, 1, 1, Direction::BottomRight),
+            (11, 12, 0, 1, Direction::Bottom),
+            (12, 13, 1, 1, Direction::BottomRight),
+        ]
+        .map(|(x, y, dx, dy, direction)| Step {
+            x,
+            y,
+            dx,
+            dy,
+            direction,
+        });
+
+        let positions = steps_to_positions(room_name, &steps);
+        assert_eq!(positions.len(), 3);
+
+        let mut previous = origin;
+        for (pos, step) in positions.iter().zip(&steps) {
+            assert_eq!(pos.room_name(), room_name);
+            assert_eq!(previous.get_range_to(*pos), 1);
+            assert_eq!(previous.get_direction_to(*pos), Some(step.direction));
+            previous = *pos;
+        }
+        assert_eq!((previous.x().u8(), previous.y().u8()), (12, 13));
+    }
+
+    #[test]
+    fn most_complete_picks_highest_ratio() {
+        let sites = [
+            ("road", 150, 300),
+            ("extension", 2_400, 3_000),
+            ("tower", 3_000, 5_000),
+            ("container", 4_000, 5_000),
+            ("spawn", 12_000, 15_000),
+        ];
+        // the extension and container are both 80% complete; the first wins
+        assert_eq!(
+            most_complete(sites, |(_, done, total)| (*done, *total)).map(|(name, _, _)| name),
+            Some("extension")
+        );
+
+        let sites = [("rampart", 0, 1), ("wall", 1, 1), ("road", 299, 300)];
+        assert_eq!(
+            most_complete(sites, |(_, done, total)| (*done, *total)).map(|(name, _, _)| name),
+            Some("wall")
+        );
+
+        assert_eq!(most_complete(Vec::<(u32, u32)>::new(), |site| *site), None);
+    }
+
+    #[test]
+    fn place_until_full_mixed_batch() {
+        let placements = [
+            (10, 10, StructureType::Extension),
+            (0, 0, StructureType::Road),
+            (11, 10, StructureType::Extension),
+            (12, 10, StructureType::Extension),
+            (13, 10, StructureType::Extension),
+        ];
+        let mut remaining_sites = 2;
+        let mut attempted = Vec::new();
+
+        let results = place_until_full(&placements, |x, y, ty| {
+            attempted.push((x, y, ty));
+            if x == 0 || y == 0 {
+                Err(ErrorCode::InvalidTarget)
+            } else if remaining_sites == 0 {
+                Err(ErrorCode::Full)
+            } else {
+                remaining_sites -= 1;
+                Ok(())
+            }
+        });
+
+        assert_eq!(
+            results,
+            vec![
+                Ok(()),
+                Err(ErrorCode::InvalidTarget),
+                Ok(()),
+                Err(ErrorCode::Full),
+                Err(ErrorCode::Full),
+            ]
+        );
+        // nothing is attempted after the limit is hit
+        assert_eq!(attempted.len(), 4);
+    }
+
+    #[test]
+    fn group_by_tile_places_results() {
+        let positioned = |x, y, terrain| PositionedLookResult {
+            x,
+            y,
+            look_result: LookResult::Terrain(terrain),
+        };
+        let grid = group_by_tile([
+            positioned(3, 4, Terrain::Plain),
+            positioned(5, 4, Terrain::Wall),
+            positioned(3, 4, Terrain::Swamp),
+        ]);
+
+        assert_eq!(grid.len(), 2);
+        assert!(matches!(
+            grid[&(3, 4)].as_slice(),
+            [
+                LookResult::Terrain(Terrain::Plain),
+                LookResult::Terrain(Terrain::Swamp)
+            ]
+        ));
+        assert!(matches!(
+            grid[&(5, 4)].as_slice(),
+            [LookResult::Terrain(Terrain::Wall)]
+        ));
+        assert!(!grid.contains_key(&(4, 4)));
+    }
+
+    #[test]
+    fn group_by_buckets_by_key() {
+        let groups = group_by(
+            [
+                (StructureType::Tower, 1),
+                (StructureType::Lab, 2),
+                (StructureType::Tower, 3),
+                (StructureType::Lab, 4),
+                (StructureType::Spawn, 5),
+            ],
+            |pair| pair,
+        );
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&StructureType::Tower], vec![1, 3]);
+        assert_eq!(groups[&StructureType::Lab], vec![2, 4]);
+        assert_eq!(groups[&StructureType::Spawn], vec![5]);
+        assert!(!groups.contains_key(&StructureType::Extension));
+    }
+
+    #[test]
+    fn room_intel_serde_roundtrip() {
+        let intel = RoomIntel {
+            room_name: "W7N3".parse().unwrap(),
+            tick: 12_345,
+            owner: Some("Invader".to_owned()),
+            controller_level: Some(4),
+            tower_count: 1,
+            hostile_count: 3,
+            mineral: Some(ResourceType::Keanium),
+        };
+
+        let json = serde_json::to_value(&intel).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "roomName": "W7N3",
+                "tick": 12_345,
+                "owner": "Invader",
+                "controllerLevel": 4,
+                "towerCount": 1,
+                "hostileCount": 3,
+                "mineral": "K",
+            })
+        );
+        assert_eq!(serde_json::from_value::<RoomIntel>(json).unwrap(), intel);
+
+        // a highway room, without a controller or mineral
+        let highway = RoomIntel {
+            room_name: "W10N3".parse().unwrap(),
+            tick: 1,
+            owner: None,
+            controller_level: None,
+            tower_count: 0,
+            hostile_count: 0,
+            mineral: None,
+        };
+        let json = serde_json::to_string(&highway).unwrap();
+        assert_eq!(serde_json::from_str::<RoomIntel>(&json).unwrap(), highway);
+    }
+
+    #[test]
+    fn filter_events_keeps_one_type() {
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            {
+                "event": 1,
+                "objectId": "5bb9a2a4a9bd0a3e4c1d2f01",
+                "data": { "targetId": "5bb9a2a4a9bd0a3e4c1d2f10", "damage": 30, "attackType": 1 }
+            },
+            {
+                "event": 6,
+                "objectId": "5bb9a2a4a9bd0a3e4c1d2f03",
+                "data": { "targetId": "5bb9a2a4a9bd0a3e4c1d2f10", "amount": 12, "healType": 1 }
+            },
+            { "event": 3, "objectId": "5bb9a2a4a9bd0a3e4c1d2f04" },
+            {
+                "event": 1,
+                "objectId": "5bb9a2a4a9bd0a3e4c1d2f02",
+                "data": { "targetId": "5bb9a2a4a9bd0a3e4c1d2f11", "damage": 10, "attackType": 2 }
+            }
+        ]))
+        .unwrap();
+
+        let attacks = filter_events(events.clone(), EventTypeDiscriminant::Attack);
+        assert_eq!(attacks, [events[0].clone(), events[3].clone()]);
+
+        let heals = filter_events(events.clone(), EventTypeDiscriminant::Heal);
+        assert_eq!(heals, [events[1].clone()]);
+
+        assert!(filter_events(events, EventTypeDiscriminant::Transfer).is_empty());
+    }
+
+    #[test]
+    fn damage_by_target_sums_attacks() {
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            {
+                "event": 1,
+                "objectId": "5bb9a2a4a9bd0a3e4c1d2f01",
+                "data": { "targetId": "5bb9a2a4a9bd0a3e4c1d2f10", "damage": 30, "attackType": 1 }
+            },
+            {
+                "event": 1,
+                "objectId": "5bb9a2a4a9bd0a3e4c1d2f02",
+                "data": { "targetId": "5bb9a2a4a9bd0a3e4c1d2f10", "damage": 10, "attackType": 2 }
+            },
+            {
+                "event": 1,
+                "objectId": "5bb9a2a4a9bd0a3e4c1d2f10",
+                "data": { "targetId": "5bb9a2a4a9bd0a3e4c1d2f01", "damage": 60, "attackType": 5 }
+            },
+            {
+                "event": 6,
+                "objectId": "5bb9a2a4a9bd0a3e4c1d2f03",
+                "data": { "targetId": "5bb9a2a4a9bd0a3e4c1d2f10", "amount": 12, "healType": 1 }
+            }
+        ]))
+        .unwrap();
+
+        let damage = damage_by_target(&events);
+        let id = |id: &str| id.parse::<RawObjectId>().unwrap();
+        assert_eq!(damage.len(), 2);
+        assert_eq!(damage[&id("5bb9a2a4a9bd0a3e4c1d2f10")], 40);
+        assert_eq!(damage[&id("5bb9a2a4a9bd0a3e4c1d2f01")], 60);
+
+        assert!(damage_by_target(&[]).is_empty());
+    }
+
+    #[test]
+    fn event_target_id() {
+        let attack = EventType::Attack(AttackEvent {
+            target_id: "attacked".to_owned(),
+            damage: 30,
+            attack_type: AttackType::Melee,
+        });
+        assert_eq!(attack.target_id(), Some("attacked"));
+
+        let transfer = EventType::Transfer(TransferEvent {
+            target_id: "receiver".to_owned(),
+            resource_type: ResourceType::Energy,
+            amount: 50,
+        });
+        assert_eq!(transfer.target_id(), Some("receiver"));
+
+        let upgrade = EventType::UpgradeController(UpgradeControllerEvent {
+            amount: 1,
+            energy_spent: 1,
+        });
+        assert_eq!(upgrade.target_id(), None);
+        assert_eq!(EventType::AttackController.target_id(), None);
+    }
+
+    #[test]
+    fn place_until_full_empty_batch() {
+        let results = place_until_full(&[], |_, _, _| unreachable!());
+        assert!(results.is_empty());
+    }
+}
@@ -3,7 +3,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     constants::{ErrorCode, Terrain},
-    local::{RoomName, RoomXY},
+    local::{RoomName, RoomXY, TerrainCounts},
     prelude::*,
 };
 
@@ -72,6 +72,14 @@ impl RoomTerrain {
         }
     }
 
+    /// Counts the plain, swamp, and wall tiles in the room, from a single copy
+    /// of the [raw buffer].
+    ///
+    /// [raw buffer]: RoomTerrain::get_raw_buffer
+    pub fn tile_counts(&self) -> TerrainCounts {
+        TerrainCounts::from_bytes(self.get_raw_buffer().to_vec())
+    }
+
     /// Get the type of terrain at the given [`RoomXY`].
     #[inline]
     pub fn get_xy(&mut self, xy: RoomXY) -> Terrain {
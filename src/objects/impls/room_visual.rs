@@ -410,4 +410,27 @@ impl RoomVisual {
     pub fn text(&self, x: f32, y: f32, text: String, style: Option<TextStyle>) {
         self.draw(&Visual::text(x, y, text, style));
     }
+
+    /// Remove all visuals drawn to this room so far in the current tick.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#RoomVisual.clear)
+    pub fn clear(&self) {
+        let name: Option<JsString> = self.room_name.map(|name| name.to_string().into());
+
+        crate::console::clear_visual(name.as_ref());
+    }
+
+    /// Get the size, in UTF-16 units, of the visuals drawn to this room so far
+    /// in the current tick. Visuals beyond [`ROOM_VISUAL_PER_ROOM_SIZE_LIMIT`]
+    /// are not displayed.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#RoomVisual.getSize)
+    ///
+    /// [`ROOM_VISUAL_PER_ROOM_SIZE_LIMIT`]:
+    /// crate::constants::ROOM_VISUAL_PER_ROOM_SIZE_LIMIT
+    pub fn get_size(&self) -> u32 {
+        let name: Option<JsString> = self.room_name.map(|name| name.to_string().into());
+
+        crate::console::get_visual_size(name.as_ref())
+    }
 }
@@ -1,7 +1,12 @@
 use js_sys::JsString;
 use wasm_bindgen::prelude::*;
 
-use crate::{objects::RoomObject, prelude::*};
+use crate::{
+    constants::Terrain,
+    local::Position,
+    objects::{RoomObject, RoomTerrain},
+    prelude::*,
+};
 
 #[wasm_bindgen]
 extern "C" {
@@ -53,6 +58,49 @@ extern "C" {
     pub fn ticks_to_regeneration(this: &Source) -> Option<u32>;
 }
 
+impl Source {
+    /// Get the walkable positions adjacent to this source, from which a creep
+    /// can harvest it.
+    ///
+    /// Uses the room's terrain, so this works without vision of the room, but
+    /// doesn't account for structures or creeps occupying these positions.
+    pub fn harvest_positions(&self) -> Vec<Position> {
+        let pos = self.pos();
+        let room_name = pos.room_name();
+
+        let terrain = match RoomTerrain::new(room_name) {
+            Some(terrain) => terrain,
+            None => return Vec::new(),
+        };
+
+        pos.xy()
+            .neighbors()
+            .into_iter()
+            .filter(|xy| {
+                matches!(
+                    terrain.get(xy.x.u8(), xy.y.u8()),
+                    Terrain::Plain | Terrain::Swamp
+                )
+            })
+            .map(|xy| Position::new(xy.x, xy.y, room_name))
+            .collect()
+    }
+
+    /// Whether a [`StructureContainer`] at `pos` would serve as a mining spot
+    /// for this source, letting a creep standing on it harvest the source with
+    /// any overflowing energy dropping into the container.
+    ///
+    /// [`StructureContainer`]: crate::objects::StructureContainer
+    pub fn is_mining_spot_container(&self, pos: Position) -> bool {
+        is_mining_spot(self.pos(), pos)
+    }
+}
+
+/// Whether `pos` is a tile adjacent to the source at `source_pos`.
+pub(crate) fn is_mining_spot(source_pos: Position, pos: Position) -> bool {
+    pos != source_pos && pos.is_near_to(source_pos)
+}
+
 impl HasId for Source {
     fn js_raw_id(&self) -> JsString {
         Self::id_internal(self)
@@ -60,3 +108,24 @@ impl HasId for Source {
 }
 
 impl Harvestable for Source {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local::RoomXY;
+
+    #[test]
+    fn mining_spots_are_adjacent() {
+        let pos = |x, y, room: &str| {
+            let xy = RoomXY::checked_new(x, y).unwrap();
+            Position::new(xy.x, xy.y, room.parse().unwrap())
+        };
+        let source = pos(20, 20, "W1N1");
+
+        assert!(is_mining_spot(source, pos(21, 21, "W1N1")));
+        assert!(is_mining_spot(source, pos(20, 19, "W1N1")));
+        assert!(!is_mining_spot(source, pos(20, 20, "W1N1")));
+        assert!(!is_mining_spot(source, pos(22, 20, "W1N1")));
+        assert!(!is_mining_spot(source, pos(21, 21, "W2N1")));
+    }
+}
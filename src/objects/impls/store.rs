@@ -1,4 +1,4 @@
-use js_sys::Object;
+use js_sys::{Array, Object};
 use wasm_bindgen::prelude::*;
 
 use crate::constants::ResourceType;
@@ -9,53 +9,243 @@ use crate::constants::ResourceType;
 extern "C" {
     /// An object that represents the cargo within an entity in the game world.
     ///
+    /// Each [`Store`] is a reference to the object's JS store, so holding on
+    /// to one from [`HasStore::store`] and querying it repeatedly avoids
+    /// re-reading the `store` property from the parent object each time.
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Store)
+    ///
+    /// [`HasStore::store`]: crate::traits::HasStore::store
     #[wasm_bindgen]
     pub type Store;
 
     #[wasm_bindgen(method, structural, indexing_getter)]
-    pub fn get(this: &Store, ty: ResourceType) -> Option<u32>;
+    fn get_internal(this: &Store, ty: &JsValue) -> Option<u32>;
+
+    #[wasm_bindgen(method, js_name = getCapacity)]
+    fn get_capacity_internal(this: &Store, ty: &JsValue) -> Option<u32>;
+
+    #[wasm_bindgen(method, js_name = getFreeCapacity)]
+    fn get_free_capacity_internal(this: &Store, ty: &JsValue) -> Option<i32>;
+
+    #[wasm_bindgen(method, js_name = getUsedCapacity)]
+    fn get_used_capacity_internal(this: &Store, ty: &JsValue) -> Option<u32>;
+}
+
+/// The JS value for an optional resource type, using the cached string from
+/// [`ResourceType::to_js_value`].
+fn resource_js_value(ty: Option<ResourceType>) -> JsValue {
+    ty.map_or(JsValue::UNDEFINED, ResourceType::to_js_value)
+}
+
+impl Store {
+    /// Get the amount of the given resource in the [`Store`], or `None` if the
+    /// store has none of it.
+    pub fn get(&self, ty: ResourceType) -> Option<u32> {
+        self.get_internal(&ty.to_js_value())
+    }
+
+    /// Get the types of resources currently held in this [`Store`].
+    pub fn store_types(&self) -> Vec<ResourceType> {
+        Object::keys(self.unchecked_ref())
+            .iter()
+            .filter_map(|v| ResourceType::from_js_value(&v))
+            .collect()
+    }
+
+    /// Get the amount of each resource currently held in this [`Store`],
+    /// reading all of them from the game at once.
+    pub(crate) fn contents(&self) -> Vec<(ResourceType, u32)> {
+        Object::entries(self.unchecked_ref())
+            .iter()
+            .filter_map(|entry| {
+                let entry: Array = entry.unchecked_into();
+                let ty = ResourceType::from_js_value(&entry.get(0))?;
+                let amount = entry.get(1).as_f64()? as u32;
+                Some((ty, amount))
+            })
+            .collect()
+    }
 
     /// Get the capacity of the [`Store`] for the specified resource. If the
     /// [`Store`] can contain any resource, passing `None` as the type will get
     /// the general store capacity.
     ///
+    /// Returns `None`, rather than 0, if the game reports no capacity for the
+    /// given type; this is the case for resources a store can't hold at all,
+    /// such as minerals in a [`StructureSpawn`], and for `None` on stores
+    /// which only have capacity for specific resources, such as a
+    /// [`StructureLab`]'s.
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Store.getCapacity)
-    #[wasm_bindgen(method, js_name = getCapacity)]
-    fn get_capacity_internal(this: &Store, ty: Option<ResourceType>) -> Option<u32>;
+    ///
+    /// [`StructureSpawn`]: crate::objects::StructureSpawn
+    /// [`StructureLab`]: crate::objects::StructureLab
+    pub fn get_capacity(&self, ty: Option<ResourceType>) -> Option<u32> {
+        self.get_capacity_internal(&resource_js_value(ty))
+    }
 
     /// Return the free capacity of the [`Store`] for the specified resource.
     ///
+    /// The resource is passed through to the game, so for stores with separate
+    /// capacity for each resource, such as a [`StructureLab`]'s energy and
+    /// mineral, this is the free capacity for that resource alone; for general
+    /// stores it's the same for any resource. Returns 0 where
+    /// [`Store::get_capacity`] would return `None`.
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Store.getFreeCapacity)
-    #[wasm_bindgen(method, js_name = getFreeCapacity)]
-    fn get_free_capacity_internal(this: &Store, ty: Option<ResourceType>) -> Option<i32>;
+    ///
+    /// [`StructureLab`]: crate::objects::StructureLab
+    pub fn get_free_capacity(&self, ty: Option<ResourceType>) -> i32 {
+        self.get_free_capacity_internal(&resource_js_value(ty))
+            .unwrap_or(0)
+    }
 
     /// Return the used capacity of the [`Store`] for the specified resource. If
     /// the [`Store`] can contain any resource, passing `None` as the type will
     /// get the total used capacity.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Store.getUsedCapacity)
-    #[wasm_bindgen(method, js_name = getUsedCapacity)]
-    fn get_used_capacity_internal(this: &Store, ty: Option<ResourceType>) -> Option<u32>;
-}
+    pub fn get_used_capacity(&self, ty: Option<ResourceType>) -> u32 {
+        self.get_used_capacity_internal(&resource_js_value(ty))
+            .unwrap_or(0)
+    }
 
-impl Store {
-    pub fn store_types(&self) -> Vec<ResourceType> {
-        Object::keys(self.unchecked_ref())
-            .iter()
-            .filter_map(|v| ResourceType::from_js_value(&v))
-            .collect()
+    /// Get the resource with the largest amount in this [`Store`] along with
+    /// its amount, or `None` if the store is empty.
+    ///
+    /// If several resources share the largest amount, the first listed by the
+    /// game is returned.
+    pub fn dominant_resource(&self) -> Option<(ResourceType, u32)> {
+        largest_resource(
+            self.store_types()
+                .into_iter()
+                .map(|ty| (ty, self.get(ty).unwrap_or(0))),
+        )
+    }
+
+    /// Get the total value of the resources in this [`Store`], with `value`
+    /// giving the value of one unit of each resource type.
+    ///
+    /// Useful for choosing which [`Tombstone`] or [`Ruin`] to loot first; for
+    /// instance, `|ty| u64::from(ty != ResourceType::Energy)` counts the
+    /// amount of all resources other than energy.
+    ///
+    /// [`Tombstone`]: crate::objects::Tombstone
+    /// [`Ruin`]: crate::objects::Ruin
+    pub fn loot_value(&self, value: impl Fn(ResourceType) -> u64) -> u64 {
+        weighted_sum(
+            self.store_types()
+                .into_iter()
+                .map(|ty| (ty, self.get(ty).unwrap_or(0))),
+            value,
+        )
     }
+}
+
+/// Picks the resource with the largest amount, preferring the first listed on
+/// ties.
+fn largest_resource(
+    amounts: impl IntoIterator<Item = (ResourceType, u32)>,
+) -> Option<(ResourceType, u32)> {
+    amounts.into_iter().filter(|(_, amount)| *amount > 0).fold(
+        None,
+        |largest: Option<(ResourceType, u32)>, (ty, amount)| match largest {
+            Some((_, largest_amount)) if largest_amount >= amount => largest,
+            _ => Some((ty, amount)),
+        },
+    )
+}
+
+/// Splits the resource with the largest amount, as picked by
+/// [`largest_resource`], from the other resources with a nonzero amount.
+pub(crate) fn split_largest(
+    contents: Vec<(ResourceType, u32)>,
+) -> Option<(ResourceType, Vec<(ResourceType, u32)>)> {
+    let (largest, _) = largest_resource(contents.iter().copied())?;
+    let rest = contents
+        .into_iter()
+        .filter(|(ty, amount)| *ty != largest && *amount > 0)
+        .collect();
+    Some((largest, rest))
+}
 
-    pub fn get_capacity(&self, ty: Option<ResourceType>) -> u32 {
-        self.get_capacity_internal(ty).unwrap_or(0)
+fn weighted_sum(
+    amounts: impl IntoIterator<Item = (ResourceType, u32)>,
+    value: impl Fn(ResourceType) -> u64,
+) -> u64 {
+    amounts
+        .into_iter()
+        .map(|(ty, amount)| amount as u64 * value(ty))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn weighted_sum_of_resources() {
+        let amounts = [
+            (ResourceType::Energy, 500),
+            (ResourceType::Hydrogen, 20),
+            (ResourceType::CatalyzedGhodiumAlkalide, 30),
+            (ResourceType::Power, 10),
+        ];
+
+        let non_energy = weighted_sum(amounts, |ty| u64::from(ty != ResourceType::Energy));
+        assert_eq!(non_energy, 60);
+
+        let value = |ty| match ty {
+            ResourceType::Energy => 1,
+            ResourceType::Power => 50,
+            ResourceType::CatalyzedGhodiumAlkalide => 20,
+            _ => 5,
+        };
+        assert_eq!(
+            weighted_sum(amounts, value),
+            500 + 20 * 5 + 30 * 20 + 10 * 50
+        );
+        assert_eq!(weighted_sum([], value), 0);
     }
 
-    pub fn get_free_capacity(&self, ty: Option<ResourceType>) -> i32 {
-        self.get_free_capacity_internal(ty).unwrap_or(0)
+    #[test]
+    fn split_largest_leaves_the_rest() {
+        assert_eq!(split_largest(Vec::new()), None);
+        assert_eq!(split_largest(vec![(ResourceType::Energy, 0)]), None);
+        assert_eq!(
+            split_largest(vec![(ResourceType::Energy, 50)]),
+            Some((ResourceType::Energy, Vec::new()))
+        );
+        assert_eq!(
+            split_largest(vec![
+                (ResourceType::Energy, 20),
+                (ResourceType::Hydrogen, 50),
+                (ResourceType::Oxygen, 0),
+                (ResourceType::Utrium, 10),
+            ]),
+            Some((
+                ResourceType::Hydrogen,
+                vec![(ResourceType::Energy, 20), (ResourceType::Utrium, 10)]
+            ))
+        );
     }
 
-    pub fn get_used_capacity(&self, ty: Option<ResourceType>) -> u32 {
-        self.get_used_capacity_internal(ty).unwrap_or(0)
+    #[test]
+    fn largest_resource_picks_one_type() {
+        assert_eq!(largest_resource([]), None);
+        assert_eq!(largest_resource([(ResourceType::Energy, 0)]), None);
+        assert_eq!(
+            largest_resource([
+                (ResourceType::Energy, 20),
+                (ResourceType::Hydrogen, 50),
+                (ResourceType::Oxygen, 10),
+            ]),
+            Some((ResourceType::Hydrogen, 50))
+        );
+        assert_eq!(
+            largest_resource([(ResourceType::Oxygen, 30), (ResourceType::Energy, 30)]),
+            Some((ResourceType::Oxygen, 30))
+        );
     }
 }
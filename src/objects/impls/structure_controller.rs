@@ -2,7 +2,7 @@ use js_sys::Date;
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    constants::ErrorCode,
+    constants::{controller_levels, safe_mode_threshold, ErrorCode},
     objects::{OwnedStructure, RoomObject, Structure},
     prelude::*,
 };
@@ -37,7 +37,8 @@ extern "C" {
     pub fn progress(this: &StructureController) -> Option<u32>;
 
     /// The total [`StructureController::progress`] needed to upgrade the
-    /// controller to the next level, or `None` if the controller is unowned.
+    /// controller to the next level, or `None` if the controller is unowned or
+    /// already at level 8.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#StructureController.progressTotal)
     #[wasm_bindgen(method, getter = progressTotal)]
@@ -85,7 +86,11 @@ extern "C" {
     /// decremented due to a lack of [`Creep::upgrade_controller`] activity, or
     /// `None` if the controller is unowned.
     ///
+    /// The maximum for each level is given by [`controller_downgrade`].
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#StructureController.ticksToDowngrade)
+    ///
+    /// [`controller_downgrade`]: crate::constants::controller_downgrade
     #[wasm_bindgen(method, getter = ticksToDowngrade)]
     pub fn ticks_to_downgrade(this: &StructureController) -> Option<u32>;
 
@@ -114,6 +119,53 @@ impl StructureController {
         ErrorCode::result_from_i8(self.activate_safe_mode_internal())
     }
 
+    /// Whether safe mode can currently be activated on this controller.
+    ///
+    /// Checks that the controller is owned by you, has a safe mode activation
+    /// available, isn't already in safe mode or on cooldown, isn't blocked by
+    /// [`Creep::attack_controller`], and that its downgrade timer is above the
+    /// [`safe_mode_threshold`] for its level.
+    ///
+    /// This can't detect safe mode being active in another of your rooms,
+    /// which will still cause [`StructureController::activate_safe_mode`] to
+    /// fail.
+    ///
+    /// [`Creep::attack_controller`]: crate::objects::Creep::attack_controller
+    pub fn can_activate_safe_mode(&self) -> bool {
+        safe_mode_activatable(
+            self.my(),
+            self.safe_mode_available(),
+            self.safe_mode_cooldown(),
+            self.safe_mode(),
+            self.upgrade_blocked(),
+            self.ticks_to_downgrade(),
+            self.level(),
+        )
+    }
+
+    /// The remaining progress needed to upgrade the controller to the next
+    /// level, or `None` if the controller is unowned or already at level 8.
+    pub fn progress_to_next_level(&self) -> Option<u32> {
+        remaining_progress(self.level(), self.progress())
+    }
+
+    /// Whether the controller's [`ticks_to_downgrade`] has fallen below
+    /// `margin`, meaning it needs upgrading soon to avoid losing a level.
+    ///
+    /// Always `false` for an unowned controller.
+    ///
+    /// [`ticks_to_downgrade`]: StructureController::ticks_to_downgrade
+    pub fn is_downgrade_imminent(&self, margin: u32) -> bool {
+        downgrade_within(self.ticks_to_downgrade(), margin)
+    }
+
+    /// Whether safe mode is currently active in the controller's room.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#StructureController.safeMode)
+    pub fn is_safe_mode_active(&self) -> bool {
+        self.safe_mode().is_some_and(|ticks| ticks > 0)
+    }
+
     /// Relinquish ownership of the controller and its room.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#StructureController.unclaim)
@@ -122,6 +174,33 @@ impl StructureController {
     }
 }
 
+fn safe_mode_activatable(
+    my: bool,
+    available: u32,
+    cooldown: Option<u32>,
+    safe_mode: Option<u32>,
+    upgrade_blocked: Option<u32>,
+    ticks_to_downgrade: Option<u32>,
+    level: u8,
+) -> bool {
+    let downgrade_threshold = safe_mode_threshold(level).unwrap_or(0);
+
+    my && available > 0
+        && safe_mode.unwrap_or(0) == 0
+        && cooldown.unwrap_or(0) == 0
+        && upgrade_blocked.unwrap_or(0) == 0
+        && ticks_to_downgrade.unwrap_or(0) >= downgrade_threshold
+}
+
+fn downgrade_within(ticks_to_downgrade: Option<u32>, margin: u32) -> bool {
+    ticks_to_downgrade.is_some_and(|ticks| ticks < margin)
+}
+
+fn remaining_progress(level: u8, progress: Option<u32>) -> Option<u32> {
+    let total = controller_levels(level as u32)?;
+    Some(total.saturating_sub(progress?))
+}
+
 #[wasm_bindgen]
 extern "C" {
     /// Object with info on who has reserved this [`StructureController`]
@@ -147,7 +226,7 @@ extern "C" {
     #[wasm_bindgen]
     pub type Sign;
 
-    /// The name of the player that has reserved this controller.
+    /// The name of the player that signed this controller.
     #[wasm_bindgen(method, getter)]
     pub fn username(this: &Sign) -> String;
 
@@ -163,3 +242,76 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn datetime(this: &Sign) -> Date;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn remaining_progress_by_level() {
+        assert_eq!(remaining_progress(1, Some(50)), Some(150));
+        assert_eq!(remaining_progress(7, Some(0)), Some(10_935_000));
+        // progress can briefly exceed the total before the upgrade applies
+        assert_eq!(remaining_progress(2, Some(50_000)), Some(0));
+        // no further progress at RCL8, or on unowned controllers
+        assert_eq!(remaining_progress(8, Some(0)), None);
+        assert_eq!(remaining_progress(0, None), None);
+        assert_eq!(remaining_progress(3, None), None);
+    }
+
+    #[test]
+    fn safe_mode_activation_blocked_by_each_condition() {
+        // an owned level 7 controller, which needs at least 70,000 ticks to
+        // downgrade
+        let activatable = |my, available, cooldown, safe_mode, upgrade_blocked, ticks| {
+            safe_mode_activatable(
+                my,
+                available,
+                cooldown,
+                safe_mode,
+                upgrade_blocked,
+                ticks,
+                7,
+            )
+        };
+
+        assert!(activatable(true, 1, None, None, None, Some(100_000)));
+        assert!(activatable(
+            true,
+            1,
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(70_000)
+        ));
+
+        assert!(!activatable(false, 1, None, None, None, Some(100_000)));
+        assert!(!activatable(true, 0, None, None, None, Some(100_000)));
+        assert!(!activatable(true, 1, Some(1), None, None, Some(100_000)));
+        assert!(!activatable(
+            true,
+            1,
+            None,
+            Some(19_999),
+            None,
+            Some(100_000)
+        ));
+        assert!(!activatable(true, 1, None, None, Some(500), Some(100_000)));
+        assert!(!activatable(true, 1, None, None, None, Some(69_999)));
+        assert!(!activatable(true, 1, None, None, None, None));
+
+        // low level controllers have no downgrade threshold
+        assert!(safe_mode_activatable(true, 1, None, None, None, Some(1), 2));
+    }
+
+    #[test]
+    fn downgrade_imminent_below_margin() {
+        assert!(downgrade_within(Some(4_999), 5_000));
+        assert!(!downgrade_within(Some(5_000), 5_000));
+        assert!(!downgrade_within(Some(20_000), 5_000));
+        assert!(downgrade_within(Some(0), 1));
+        assert!(!downgrade_within(Some(0), 0));
+        // unowned controllers never downgrade
+        assert!(!downgrade_within(None, 5_000));
+    }
+}
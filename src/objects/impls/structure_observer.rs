@@ -2,7 +2,8 @@ use js_sys::JsString;
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    constants::ErrorCode,
+    constants::{ErrorCode, OBSERVER_RANGE},
+    game,
     local::RoomName,
     objects::{OwnedStructure, RoomObject, Structure},
     prelude::*,
@@ -37,3 +38,177 @@ impl StructureObserver {
 impl Attackable for StructureObserver {}
 impl Dismantleable for StructureObserver {}
 impl Repairable for StructureObserver {}
+
+/// Schedules [`StructureObserver::observe_room`] calls to cycle through a set
+/// of rooms, with each observer scanning at most one room per tick.
+///
+/// Rooms are scanned round-robin in the order given, with each observer
+/// taking the next room in the rotation within [`OBSERVER_RANGE`] of it.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::{game, objects::ScanScheduler, StructureObserver};
+///
+/// let rooms = ["W1N1", "W2N1", "W3N1"].map(|name| name.parse().unwrap());
+/// let mut scheduler = ScanScheduler::new(rooms);
+///
+/// // each tick
+/// let observers: Vec<StructureObserver> = vec![/* your observers */];
+/// scheduler.run(&observers);
+/// // on the next tick, these rooms will be visible
+/// let visible_soon = scheduler.observed().to_vec();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ScanScheduler {
+    rooms: Vec<RoomName>,
+    next: usize,
+    last_tick: Option<u32>,
+    observed: Vec<RoomName>,
+}
+
+impl ScanScheduler {
+    /// Create a scheduler cycling through the given rooms. Duplicates are
+    /// ignored.
+    pub fn new(rooms: impl IntoIterator<Item = RoomName>) -> Self {
+        let mut unique = Vec::new();
+        for room in rooms {
+            if !unique.contains(&room) {
+                unique.push(room);
+            }
+        }
+
+        ScanScheduler {
+            rooms: unique,
+            ..Default::default()
+        }
+    }
+
+    /// The rooms this scheduler cycles through.
+    pub fn rooms(&self) -> &[RoomName] {
+        &self.rooms
+    }
+
+    /// The rooms successfully requested on the last call to
+    /// [`ScanScheduler::run`], which will be visible on the following tick.
+    pub fn observed(&self) -> &[RoomName] {
+        &self.observed
+    }
+
+    /// Have each observer scan the next room in the rotation that's in its
+    /// range, returning the rooms which will be visible next tick.
+    ///
+    /// Calling this again in the same tick does nothing, since each
+    /// observer's last request would replace its earlier one.
+    pub fn run(&mut self, observers: &[StructureObserver]) -> &[RoomName] {
+        let observer_rooms: Vec<RoomName> = observers.iter().map(|o| o.pos().room_name()).collect();
+
+        if let Some(plan) = self.plan(game::time(), &observer_rooms) {
+            self.observed = plan
+                .into_iter()
+                .filter(|&(observer, room)| observers[observer].observe_room(room).is_ok())
+                .map(|(_, room)| room)
+                .collect();
+        }
+
+        &self.observed
+    }
+
+    /// Pick a room for each observer, as pairs of the observer's index and the
+    /// room, or `None` if already planned this tick.
+    fn plan(&mut self, tick: u32, observer_rooms: &[RoomName]) -> Option<Vec<(usize, RoomName)>> {
+        if self.last_tick == Some(tick) {
+            return None;
+        }
+        self.last_tick = Some(tick);
+
+        let mut plan: Vec<(usize, RoomName)> = Vec::new();
+        for (observer, &from) in observer_rooms.iter().enumerate() {
+            let len = self.rooms.len();
+            let found = (0..len)
+                .map(|offset| (self.next + offset) % len)
+                .find(|&idx| {
+                    let room = self.rooms[idx];
+                    let (dx, dy) = room - from;
+                    dx.unsigned_abs().max(dy.unsigned_abs()) <= OBSERVER_RANGE
+                        && plan.iter().all(|&(_, planned)| planned != room)
+                });
+
+            if let Some(idx) = found {
+                plan.push((observer, self.rooms[idx]));
+                self.next = (idx + 1) % len;
+            }
+        }
+
+        Some(plan)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn room(name: &str) -> RoomName {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn cycles_through_rooms() {
+        let rooms = [room("W1N1"), room("W2N1"), room("W3N1"), room("W1N1")];
+        let mut scheduler = ScanScheduler::new(rooms);
+        assert_eq!(scheduler.rooms().len(), 3);
+
+        let observer = [room("W2N2")];
+        let scanned: Vec<RoomName> = (0..6)
+            .map(|tick| {
+                let plan = scheduler.plan(tick, &observer).unwrap();
+                // one room per observer per tick
+                assert_eq!(plan.len(), 1);
+                plan[0].1
+            })
+            .collect();
+
+        assert_eq!(
+            scanned,
+            ["W1N1", "W2N1", "W3N1", "W1N1", "W2N1", "W3N1"].map(room)
+        );
+    }
+
+    #[test]
+    fn one_scan_per_observer_per_tick() {
+        let rooms = ["W1N1", "W2N1", "W3N1"].map(room);
+        let mut scheduler = ScanScheduler::new(rooms);
+        let observers = [room("W2N2"), room("W2N3")];
+
+        assert_eq!(
+            scheduler.plan(10, &observers),
+            Some(vec![(0, room("W1N1")), (1, room("W2N1"))])
+        );
+        // already scheduled this tick
+        assert_eq!(scheduler.plan(10, &observers), None);
+        // two observers never scan the same room, even with fewer rooms
+        assert_eq!(
+            scheduler.plan(11, &observers),
+            Some(vec![(0, room("W3N1")), (1, room("W1N1"))])
+        );
+
+        let mut single = ScanScheduler::new([room("W1N1")]);
+        assert_eq!(single.plan(10, &observers), Some(vec![(0, room("W1N1"))]));
+    }
+
+    #[test]
+    fn skips_rooms_out_of_range() {
+        let rooms = ["W1N1", "W30N1", "W2N1"].map(room);
+        let mut scheduler = ScanScheduler::new(rooms);
+        let observer = [room("W1N2")];
+
+        let scanned: Vec<RoomName> = (0..4)
+            .flat_map(|tick| scheduler.plan(tick, &observer).unwrap())
+            .map(|(_, room)| room)
+            .collect();
+        assert_eq!(scanned, ["W1N1", "W2N1", "W1N1", "W2N1"].map(room));
+
+        let mut unreachable = ScanScheduler::new([room("W30N1")]);
+        assert_eq!(unreachable.plan(0, &observer), Some(vec![]));
+    }
+}
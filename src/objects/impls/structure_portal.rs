@@ -29,6 +29,10 @@ extern "C" {
 }
 
 impl StructurePortal {
+    /// The destination of this portal - either a position in the same shard,
+    /// or a room on another shard.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#StructurePortal.destination)
     pub fn destination(&self) -> PortalDestination {
         let dest = Self::destination_internal(self);
         match dest.dyn_ref::<RoomPosition>() {
@@ -44,6 +48,9 @@ impl CanDecay for StructurePortal {
     }
 }
 
+/// The destination of a [`StructurePortal`], which is either a
+/// [`Position`] in the current shard or a room on another shard.
+#[derive(Clone, Debug)]
 pub enum PortalDestination {
     InterRoom(Position),
     InterShard(InterShardPortalDestination),
@@ -56,16 +63,19 @@ extern "C" {
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#StructurePortal.destination)
     #[wasm_bindgen]
+    #[derive(Clone, Debug)]
     pub type InterShardPortalDestination;
 
     #[wasm_bindgen(method, getter = room)]
     fn room_internal(this: &InterShardPortalDestination) -> JsString;
 
+    /// The name of the shard this portal leads to.
     #[wasm_bindgen(method, getter)]
     pub fn shard(this: &InterShardPortalDestination) -> String;
 }
 
 impl InterShardPortalDestination {
+    /// The name of the room on the destination shard this portal leads to.
     pub fn room(&self) -> RoomName {
         Self::room_internal(self)
             .try_into()
@@ -1,7 +1,7 @@
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    constants::ErrorCode,
+    constants::{ErrorCode, ResourceType, POWER_SPAWN_ENERGY_RATIO},
     objects::{OwnedStructure, RoomObject, Store, Structure},
     prelude::*,
 };
@@ -43,6 +43,23 @@ impl StructurePowerSpawn {
     pub fn process_power(&self) -> Result<(), ErrorCode> {
         ErrorCode::result_from_i8(self.process_power_internal())
     }
+
+    /// Whether the power spawn holds enough power and energy for
+    /// [`StructurePowerSpawn::process_power`] - at least 1 power and
+    /// [`POWER_SPAWN_ENERGY_RATIO`] energy.
+    ///
+    /// [`POWER_SPAWN_ENERGY_RATIO`]: crate::constants::POWER_SPAWN_ENERGY_RATIO
+    pub fn can_process_power(&self) -> bool {
+        let store = self.store();
+        enough_to_process(
+            store.get_used_capacity(Some(ResourceType::Power)),
+            store.get_used_capacity(Some(ResourceType::Energy)),
+        )
+    }
+}
+
+fn enough_to_process(power: u32, energy: u32) -> bool {
+    power >= 1 && energy >= POWER_SPAWN_ENERGY_RATIO
 }
 
 impl HasStore for StructurePowerSpawn {
@@ -56,3 +73,19 @@ impl Dismantleable for StructurePowerSpawn {}
 impl Repairable for StructurePowerSpawn {}
 impl Transferable for StructurePowerSpawn {}
 impl Withdrawable for StructurePowerSpawn {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn enough_to_process_thresholds() {
+        assert!(enough_to_process(1, POWER_SPAWN_ENERGY_RATIO));
+        assert!(enough_to_process(100, 5_000));
+
+        assert!(!enough_to_process(0, POWER_SPAWN_ENERGY_RATIO));
+        assert!(!enough_to_process(0, 5_000));
+        assert!(!enough_to_process(1, POWER_SPAWN_ENERGY_RATIO - 1));
+        assert!(!enough_to_process(100, 0));
+    }
+}
@@ -3,10 +3,15 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     constants::{Direction, ErrorCode, Part},
+    game,
     objects::{Creep, OwnedStructure, RoomObject, Store, Structure},
     prelude::*,
 };
 
+/// Maximum number of names tried by [`StructureSpawn::spawn_creep_auto`]
+/// before giving up.
+const SPAWN_CREEP_AUTO_NAME_ATTEMPTS: u32 = 10;
+
 #[wasm_bindgen]
 extern "C" {
     /// An object representing a [`StructureSpawn`], which creates your creeps.
@@ -40,9 +45,16 @@ extern "C" {
     #[wasm_bindgen(method, getter = name)]
     pub fn name_jsstring(this: &StructureSpawn) -> JsString;
 
-    /// Information about the spawning creep, if one is currently being spawned.
+    /// Information about the spawning creep, if one is currently being spawned,
+    /// or `None` if the spawn is idle.
+    ///
+    /// The returned [`Spawning`] can be used to [`cancel`] the spawn or
+    /// [`set_directions`] the creep may exit in.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#StructureSpawn.spawning)
+    ///
+    /// [`cancel`]: Spawning::cancel
+    /// [`set_directions`]: Spawning::set_directions
     #[wasm_bindgen(method, getter)]
     pub fn spawning(this: &StructureSpawn) -> Option<Spawning>;
 
@@ -84,6 +96,19 @@ impl StructureSpawn {
         ErrorCode::result_from_i8(Self::spawn_creep_internal(self, &body, name, None))
     }
 
+    /// Create a new creep with the specified body part [`Array`], named with
+    /// the given prefix followed by the current tick, returning the name of
+    /// the creep if spawning succeeded.
+    ///
+    /// If the name is already in use, a counter is appended and spawning is
+    /// retried, trying up to 10 names before giving up with
+    /// [`ErrorCode::NameExists`]. Any other error is returned immediately.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#StructureSpawn.spawnCreep)
+    pub fn spawn_creep_auto(&self, body: &[Part], prefix: &str) -> Result<String, ErrorCode> {
+        spawn_with_generated_name(prefix, game::time(), |name| self.spawn_creep(body, name))
+    }
+
     /// Create a new creep with the specified body part [`Array`], name
     /// [`JsString`], and optional spawning options. Note that successfully
     /// spawning will store data in `Memory.creeps[creep_name]` _regardless
@@ -137,7 +162,8 @@ impl StructureSpawn {
     }
 
     /// Renew a [`Creep`] in melee range, removing all boosts adding to its TTL.
-    /// Cannot be used while spawning.
+    /// Cannot be used while spawning, returning [`ErrorCode::Busy`] if
+    /// [`StructureSpawn::spawning`] is `Some`.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#StructureSpawn.renewCreep)
     pub fn renew_creep(&self, creep: &Creep) -> Result<(), ErrorCode> {
@@ -181,9 +207,41 @@ impl SpawnOptions {
         self
     }
 
+    /// Set the structures to draw energy from for spawning, in the order
+    /// they'll be drawn from, instead of the game's default order.
+    ///
     /// Structures other than [`StructureSpawn`] and [`StructureExtension`] will
     /// be ignored.
     ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::{find, prelude::*, Part, SpawnOptions, StructureObject, StructureSpawn};
+    ///
+    /// # let spawn: StructureSpawn = todo!();
+    /// // drain the extensions furthest from the spawn first, keeping the near
+    /// // ones full for quick refills
+    /// let mut energy_structures: Vec<StructureObject> = spawn
+    ///     .room()
+    ///     .unwrap()
+    ///     .find(find::MY_STRUCTURES, None)
+    ///     .into_iter()
+    ///     .filter(|s| {
+    ///         matches!(
+    ///             s,
+    ///             StructureObject::StructureSpawn(_) | StructureObject::StructureExtension(_)
+    ///         )
+    ///     })
+    ///     .collect();
+    /// energy_structures.sort_by_key(|s| std::cmp::Reverse(spawn.pos().get_range_to(s.pos())));
+    ///
+    /// let options = SpawnOptions::new()
+    ///     .energy_structures(energy_structures.iter().map(StructureObject::as_structure));
+    /// spawn.spawn_creep_with_options(&[Part::Work, Part::Carry, Part::Move], "worker", &options);
+    /// ```
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#StructureSpawn.spawnCreep)
+    ///
     /// [`StructureExtension`]: crate::objects::StructureExtension
     pub fn energy_structures<T: IntoIterator<Item = V>, V: AsRef<Structure>>(
         mut self,
@@ -223,6 +281,7 @@ extern "C" {
     ///
     /// [`StructureInvaderCore`]: crate::objects::StructureInvaderCore
     #[wasm_bindgen(js_namespace = StructureSpawn)]
+    #[derive(Clone, Debug)]
     pub type Spawning;
 
     /// Allowed directions for the creep to exit the spawn; can be changed with
@@ -282,3 +341,70 @@ impl Spawning {
         ErrorCode::result_from_i8(self.set_directions_internal(directions))
     }
 }
+
+fn spawn_with_generated_name(
+    prefix: &str,
+    tick: u32,
+    mut spawn: impl FnMut(&str) -> Result<(), ErrorCode>,
+) -> Result<String, ErrorCode> {
+    for attempt in 0..SPAWN_CREEP_AUTO_NAME_ATTEMPTS {
+        let name = match attempt {
+            0 => format!("{prefix}{tick}"),
+            n => format!("{prefix}{tick}-{n}"),
+        };
+        match spawn(&name) {
+            Ok(()) => return Ok(name),
+            Err(ErrorCode::NameExists) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(ErrorCode::NameExists)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn generated_name_skips_existing() {
+        let mut existing: HashSet<String> = ["worker100", "worker100-1"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        let name = spawn_with_generated_name("worker", 100, |name| {
+            if existing.insert(name.to_owned()) {
+                Ok(())
+            } else {
+                Err(ErrorCode::NameExists)
+            }
+        });
+        assert_eq!(name.as_deref(), Ok("worker100-2"));
+
+        // a second spawn on the same tick gets another unique name
+        let second = spawn_with_generated_name("worker", 100, |name| {
+            if existing.insert(name.to_owned()) {
+                Ok(())
+            } else {
+                Err(ErrorCode::NameExists)
+            }
+        });
+        assert_eq!(second.as_deref(), Ok("worker100-3"));
+    }
+
+    #[test]
+    fn generated_name_gives_up() {
+        let mut attempts = 0;
+        let result = spawn_with_generated_name("x", 1, |_| {
+            attempts += 1;
+            Err(ErrorCode::NameExists)
+        });
+        assert_eq!(result, Err(ErrorCode::NameExists));
+        assert_eq!(attempts, SPAWN_CREEP_AUTO_NAME_ATTEMPTS);
+
+        let result = spawn_with_generated_name("x", 1, |_| Err(ErrorCode::NotEnough));
+        assert_eq!(result, Err(ErrorCode::NotEnough));
+    }
+}
@@ -2,7 +2,8 @@ use js_sys::JsString;
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    constants::{ErrorCode, ResourceType},
+    constants::{terminal_send_cost, ErrorCode, ResourceType},
+    game,
     local::RoomName,
     objects::{OwnedStructure, RoomObject, Store, Structure},
     prelude::*,
@@ -63,6 +64,47 @@ impl StructureTerminal {
             description.as_ref(),
         ))
     }
+
+    /// Whether the terminal currently holds enough of the resource and
+    /// energy to [`send`] `amount` of `resource` to `destination`.
+    ///
+    /// The energy cost is calculated with [`terminal_send_cost`]; sending
+    /// energy requires `amount` plus the cost in energy.
+    ///
+    /// [`send`]: StructureTerminal::send
+    pub fn can_afford_send(
+        &self,
+        resource: ResourceType,
+        amount: u32,
+        destination: RoomName,
+    ) -> bool {
+        let range = game::map::get_room_linear_distance(self.pos().room_name(), destination, true);
+        let cost = terminal_send_cost(amount, range);
+        let store = self.store();
+
+        can_afford(
+            resource,
+            amount,
+            cost,
+            store.get_used_capacity(Some(resource)),
+            store.get_used_capacity(Some(ResourceType::Energy)),
+        )
+    }
+}
+
+fn can_afford(
+    resource: ResourceType,
+    amount: u32,
+    cost: u32,
+    resource_held: u32,
+    energy_held: u32,
+) -> bool {
+    match resource {
+        ResourceType::Energy => amount
+            .checked_add(cost)
+            .is_some_and(|needed| energy_held >= needed),
+        _ => resource_held >= amount && energy_held >= cost,
+    }
 }
 
 impl HasCooldown for StructureTerminal {
@@ -82,3 +124,33 @@ impl Dismantleable for StructureTerminal {}
 impl Repairable for StructureTerminal {}
 impl Transferable for StructureTerminal {}
 impl Withdrawable for StructureTerminal {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn energy_send_needs_amount_plus_cost() {
+        let cost = terminal_send_cost(1000, 10);
+        assert_eq!(cost, 284);
+
+        assert!(can_afford(ResourceType::Energy, 1000, cost, 1284, 1284));
+        assert!(!can_afford(ResourceType::Energy, 1000, cost, 1283, 1283));
+        assert!(!can_afford(
+            ResourceType::Energy,
+            u32::MAX,
+            1,
+            u32::MAX,
+            u32::MAX
+        ));
+    }
+
+    #[test]
+    fn mineral_send_needs_amount_and_energy_cost() {
+        let cost = terminal_send_cost(1000, 10);
+
+        assert!(can_afford(ResourceType::Hydrogen, 1000, cost, 1000, 284));
+        assert!(!can_afford(ResourceType::Hydrogen, 1000, cost, 999, 5000));
+        assert!(!can_afford(ResourceType::Hydrogen, 1000, cost, 5000, 283));
+    }
+}
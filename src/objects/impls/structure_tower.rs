@@ -1,8 +1,11 @@
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    constants::ErrorCode,
-    objects::{OwnedStructure, RoomObject, Store, Structure},
+    constants::{
+        tower_power_at_range, ErrorCode, TOWER_POWER_ATTACK, TOWER_POWER_HEAL, TOWER_POWER_REPAIR,
+    },
+    local::Position,
+    objects::{Creep, OwnedStructure, RoomObject, Store, Structure},
     prelude::*,
 };
 
@@ -72,6 +75,80 @@ impl StructureTower {
     {
         ErrorCode::result_from_i8(self.repair_internal(target.as_ref()))
     }
+
+    /// Pick the hostile creep this tower would do the most damage to, after
+    /// range falloff; the closest is preferred when damage is equal.
+    ///
+    /// This doesn't account for boosted tough parts or power creep effects.
+    /// Returns `None` if there are no hostiles.
+    pub fn best_attack_target(&self, hostiles: &[Creep]) -> Option<Creep> {
+        let candidates = hostiles.iter().map(|creep| (creep.pos(), None));
+        best_tower_target(self.pos(), TOWER_POWER_ATTACK, candidates).map(|i| hostiles[i].clone())
+    }
+
+    /// Pick the damaged creep this tower would restore the most hits to, after
+    /// range falloff and capped at the hits each is missing; the closest is
+    /// preferred when healing is equal.
+    ///
+    /// Returns `None` if none of the creeps are damaged.
+    pub fn best_heal_target(&self, creeps: &[Creep]) -> Option<Creep> {
+        let candidates = creeps.iter().map(|creep| {
+            (
+                creep.pos(),
+                Some(creep.hits_max().saturating_sub(creep.hits())),
+            )
+        });
+        best_tower_target(self.pos(), TOWER_POWER_HEAL, candidates).map(|i| creeps[i].clone())
+    }
+
+    /// Pick the damaged structure this tower would restore the most hits to,
+    /// after range falloff and capped at the hits each is missing; the closest
+    /// is preferred when repair is equal.
+    ///
+    /// Returns `None` if none of the structures are damaged.
+    pub fn best_repair_target<T>(&self, structures: &[T]) -> Option<T>
+    where
+        T: Repairable + HasPosition + Clone,
+    {
+        let candidates = structures.iter().map(|structure| {
+            (
+                structure.pos(),
+                Some(structure.hits_max().saturating_sub(structure.hits())),
+            )
+        });
+        best_tower_target(self.pos(), TOWER_POWER_REPAIR, candidates).map(|i| structures[i].clone())
+    }
+}
+
+/// Picks the index of the candidate a tower at `origin` has the most effect
+/// on, given each candidate's position and the hits it's missing, if capped.
+/// Ties go to the closest, then the first listed; candidates with no effect
+/// are skipped.
+fn best_tower_target(
+    origin: Position,
+    power: u32,
+    candidates: impl Iterator<Item = (Position, Option<u32>)>,
+) -> Option<usize> {
+    candidates
+        .enumerate()
+        .filter_map(|(idx, (pos, missing_hits))| {
+            let range = origin.get_range_to(pos);
+            let effect = tower_power_at_range(power, range);
+            let effect = missing_hits.map_or(effect, |missing| effect.min(missing));
+            (effect > 0).then_some((idx, effect, range))
+        })
+        .fold(
+            None,
+            |best: Option<(usize, u32, u32)>, (idx, effect, range)| match best {
+                Some((_, best_effect, best_range))
+                    if (best_effect, u32::MAX - best_range) >= (effect, u32::MAX - range) =>
+                {
+                    best
+                }
+                _ => Some((idx, effect, range)),
+            },
+        )
+        .map(|(idx, _, _)| idx)
 }
 
 impl HasStore for StructureTower {
@@ -85,3 +162,72 @@ impl Dismantleable for StructureTower {}
 impl Repairable for StructureTower {}
 impl Transferable for StructureTower {}
 impl Withdrawable for StructureTower {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local::{RoomName, RoomXY};
+
+    fn pos(x: u8, y: u8) -> Position {
+        let xy = RoomXY::checked_new(x, y).unwrap();
+        Position::new(xy.x, xy.y, "W1N1".parse::<RoomName>().unwrap())
+    }
+
+    #[test]
+    fn attack_prefers_closer_targets() {
+        let tower = pos(25, 25);
+
+        // both within optimal range, so damage is equal
+        let candidates = [
+            (pos(29, 25), None),
+            (pos(27, 26), None),
+            (pos(40, 40), None),
+        ];
+        assert_eq!(
+            best_tower_target(tower, TOWER_POWER_ATTACK, candidates.into_iter()),
+            Some(1)
+        );
+
+        // with falloff, the closer target takes more damage
+        let candidates = [(pos(45, 25), None), (pos(25, 10), None)];
+        assert_eq!(
+            best_tower_target(tower, TOWER_POWER_ATTACK, candidates.into_iter()),
+            Some(1)
+        );
+
+        // ties at the same range go to the first listed
+        let candidates = [(pos(20, 25), None), (pos(30, 25), None)];
+        assert_eq!(
+            best_tower_target(tower, TOWER_POWER_ATTACK, candidates.into_iter()),
+            Some(0)
+        );
+
+        assert_eq!(
+            best_tower_target(tower, TOWER_POWER_ATTACK, std::iter::empty()),
+            None
+        );
+    }
+
+    #[test]
+    fn heal_is_capped_at_missing_hits() {
+        let tower = pos(25, 25);
+        let candidates = [
+            // close, but only missing 100 hits
+            (pos(26, 25), Some(100)),
+            // far enough for falloff, but would take 280 of its 1000 missing
+            (pos(25, 36), Some(1000)),
+            // undamaged
+            (pos(25, 24), Some(0)),
+        ];
+        assert_eq!(
+            best_tower_target(tower, TOWER_POWER_HEAL, candidates.into_iter()),
+            Some(1)
+        );
+
+        let undamaged = [(pos(26, 25), Some(0))];
+        assert_eq!(
+            best_tower_target(tower, TOWER_POWER_HEAL, undamaged.into_iter()),
+            None
+        );
+    }
+}
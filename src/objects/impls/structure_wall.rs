@@ -1,6 +1,7 @@
 use wasm_bindgen::prelude::*;
 
 use crate::{
+    constants::WALL_HITS_MAX,
     objects::{RoomObject, Structure},
     prelude::*,
 };
@@ -10,12 +11,29 @@ extern "C" {
     /// An object representing a [`StructureWall`], which blocks movement of all
     /// creeps.
     ///
+    /// Walls aren't owned by any player, so this type has no owner-related
+    /// methods; the hits accessors come from [`Structure`].
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#StructureWall)
     #[wasm_bindgen(extends = RoomObject, extends = Structure)]
     #[derive(Clone, Debug)]
     pub type StructureWall;
 }
 
+impl StructureWall {
+    /// The maximum hits a wall can be repaired to, regardless of the room
+    /// controller level, without needing a reference to a wall.
+    ///
+    /// ```
+    /// use screeps::{constants::WALL_HITS_MAX, objects::StructureWall};
+    ///
+    /// assert_eq!(StructureWall::wall_hits_max(), WALL_HITS_MAX);
+    /// ```
+    pub const fn wall_hits_max() -> u32 {
+        WALL_HITS_MAX
+    }
+}
+
 impl Attackable for StructureWall {}
 impl Dismantleable for StructureWall {}
 impl Repairable for StructureWall {}
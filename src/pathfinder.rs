@@ -13,6 +13,8 @@
 //!
 //! [`Room::find_path`]: crate::objects::Room::find_path
 
+use std::collections::HashMap;
+
 use js_sys::{Array, JsString, Object};
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
@@ -20,6 +22,7 @@ use wasm_bindgen::prelude::*;
 use crate::{
     local::{Position, RoomName},
     objects::{CostMatrix, RoomPosition},
+    prelude::*,
 };
 
 #[wasm_bindgen]
@@ -120,12 +123,17 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn cost(this: &SearchResults) -> u32;
 
-    /// Whether this search successfully found a complete path.
+    /// Whether this search failed to find a complete path, in which case
+    /// [`SearchResults::path`] leads to the closest position to the goal that
+    /// was found.
     #[wasm_bindgen(method, getter)]
     pub fn incomplete(this: &SearchResults) -> bool;
 }
 
 impl SearchResults {
+    /// Get the path that was found, not including the origin, as local
+    /// [`Position`]s which may span multiple rooms. Each position is at range 1
+    /// from the one before it, including across room edges.
     pub fn path(&self) -> Vec<Position> {
         self.path_internal()
             .iter()
@@ -134,6 +142,8 @@ impl SearchResults {
             .collect()
     }
 
+    /// Get the path that was found as the [`Array`] of [`RoomPosition`]s the
+    /// game returned, without converting it.
     pub fn opaque_path(&self) -> Array {
         self.path_internal()
     }
@@ -295,7 +305,7 @@ where
         self
     }
 
-    /// Sets maximum rooms - default `16`, max `16`.
+    /// Sets maximum rooms - default `16`, max `64`.
     #[inline]
     pub fn max_rooms(mut self, rooms: u8) -> Self {
         self.inner.max_rooms = Some(rooms);
@@ -303,6 +313,9 @@ where
     }
 
     /// Sets maximum path cost - default `f64::Infinity`.
+    ///
+    /// If no path is found within this cost, the search gives up early and
+    /// returns a result with [`SearchResults::incomplete`] set to `true`.
     #[inline]
     pub fn max_cost(mut self, cost: f64) -> Self {
         self.inner.max_cost = Some(cost);
@@ -317,6 +330,74 @@ where
     }
 }
 
+/// A room callback which builds each room's [`CostMatrix`] only once, no
+/// matter how many times the search asks for the room.
+///
+/// Create one per search and pass [`CachedCostCallback::into_callback`] to
+/// [`SearchOptions::new`], so the cached matrices are dropped along with the
+/// options once the search is done.
+///
+/// ```no_run
+/// use screeps::{
+///     pathfinder::{self, CachedCostCallback, SearchOptions},
+///     CostMatrix, Position,
+/// };
+///
+/// # fn build_matrix(_: screeps::RoomName) -> Option<CostMatrix> { None }
+/// # let (from, to): (Position, Position) = todo!();
+/// let callback = CachedCostCallback::new(build_matrix).into_callback();
+/// let result = pathfinder::search(from, to, 1, Some(SearchOptions::new(callback)));
+/// ```
+pub struct CachedCostCallback<B>
+where
+    B: FnMut(RoomName) -> Option<CostMatrix>,
+{
+    builder: B,
+    matrices: HashMap<RoomName, Option<CostMatrix>>,
+}
+
+impl<B> CachedCostCallback<B>
+where
+    B: FnMut(RoomName) -> Option<CostMatrix>,
+{
+    /// Wrap a function building the [`CostMatrix`] for a room, or returning
+    /// `None` to use the default costs for the room's terrain.
+    pub fn new(builder: B) -> Self {
+        CachedCostCallback {
+            builder,
+            matrices: HashMap::new(),
+        }
+    }
+
+    /// Get the cost of a room, building its [`CostMatrix`] if this is the
+    /// first time the room has been asked for.
+    pub fn cost_matrix(&mut self, room_name: RoomName) -> MultiRoomCostResult {
+        match memoized(&mut self.matrices, room_name, &mut self.builder) {
+            // another reference to the same JS matrix, rather than a copy
+            Some(matrix) => MultiRoomCostResult::CostMatrix(
+                matrix.unchecked_ref::<JsValue>().clone().unchecked_into(),
+            ),
+            None => MultiRoomCostResult::Default,
+        }
+    }
+
+    /// Convert into a room callback for [`SearchOptions`].
+    pub fn into_callback(mut self) -> impl FnMut(RoomName) -> MultiRoomCostResult {
+        move |room_name| self.cost_matrix(room_name)
+    }
+}
+
+fn memoized<M>(
+    cache: &mut HashMap<RoomName, Option<M>>,
+    room_name: RoomName,
+    build: impl FnOnce(RoomName) -> Option<M>,
+) -> Option<&M> {
+    cache
+        .entry(room_name)
+        .or_insert_with(|| build(room_name))
+        .as_ref()
+}
+
 #[wasm_bindgen]
 pub struct SearchGoal {
     pos: Position,
@@ -327,6 +408,15 @@ impl SearchGoal {
     pub fn new(pos: Position, range: u32) -> Self {
         SearchGoal { pos, range }
     }
+
+    /// Create a goal at the position of anything with a position, such as a
+    /// room object.
+    pub fn from_target(target: &impl HasPosition, range: u32) -> Self {
+        SearchGoal {
+            pos: target.pos(),
+            range,
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -342,24 +432,41 @@ impl SearchGoal {
     }
 }
 
+/// Search for a path from an origin to a single goal, stopping once within
+/// `range` of the goal.
+///
+/// Both the origin and the goal can be anything with a position, such as a
+/// [`Position`], a [`RoomPosition`], or a room object like a creep.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#PathFinder.search)
 pub fn search<F>(
-    from: Position,
-    to: Position,
+    from: impl HasPosition,
+    to: impl HasPosition,
     range: u32,
     options: Option<SearchOptions<F>>,
 ) -> SearchResults
 where
     F: FnMut(RoomName) -> MultiRoomCostResult,
 {
-    let goal = SearchGoal { pos: to, range };
+    let goal = SearchGoal {
+        pos: to.pos(),
+        range,
+    };
 
     let goal = JsValue::from(goal);
 
-    search_real(from, &goal, options)
+    search_real(from.pos(), &goal, options)
 }
 
+/// Search for a path from an origin to whichever of multiple goals is
+/// cheapest to reach.
+///
+/// The origin can be anything with a position, such as a [`Position`], a
+/// [`RoomPosition`], or a room object like a creep.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#PathFinder.search)
 pub fn search_many<F>(
-    from: Position,
+    from: impl HasPosition,
     to: impl Iterator<Item = SearchGoal>,
     options: Option<SearchOptions<F>>,
 ) -> SearchResults
@@ -368,7 +475,7 @@ where
 {
     let goals: Array = to.map(JsValue::from).collect();
 
-    search_real(from, goals.as_ref(), options)
+    search_real(from.pos(), goals.as_ref(), options)
 }
 
 fn search_real<F>(
@@ -387,3 +494,27 @@ where
         PathFinder::search_internal(&from, goal, &JsValue::UNDEFINED)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn memoized_builds_each_room_once() {
+        let mut cache = HashMap::new();
+        let mut built = Vec::new();
+        let mut build = |room_name: RoomName| {
+            built.push(room_name);
+            (room_name.x_coord() >= 0).then_some(built.len())
+        };
+
+        let e1n1: RoomName = "E1N1".parse().unwrap();
+        let w1n1: RoomName = "W1N1".parse().unwrap();
+        for room_name in [e1n1, w1n1, e1n1, w1n1, e1n1] {
+            memoized(&mut cache, room_name, &mut build);
+        }
+        assert_eq!(memoized(&mut cache, e1n1, &mut build), Some(&1));
+        assert_eq!(memoized(&mut cache, w1n1, &mut build), None);
+        assert_eq!(built, [e1n1, w1n1]);
+    }
+}
@@ -10,6 +10,11 @@
 //! Also contains functions for accessing memory segments and other
 //! players' active foreign segments.
 //!
+//! Every function here looks up the `RawMemory` global when it's called, so
+//! unlike [`memory::ROOT`], no reference is held across ticks and it's always
+//! the current tick's object being read or written.
+//!
+//! [`memory::ROOT`]: crate::memory::ROOT
 //! [`RawMemory`]: https://docs.screeps.com/api/#RawMemory
 use js_sys::{Array, JsString, Object};
 
@@ -74,6 +79,9 @@ pub fn foreign_segment() -> Option<ForeignSegment> {
 
 /// Get the stored serialized memory as a [`JsString`].
 ///
+/// This is the data as it was at the start of the tick, or as last written by
+/// [`set`] this tick.
+///
 /// [Screeps documentation](https://docs.screeps.com/api/#RawMemory.get)
 pub fn get() -> JsString {
     RawMemory::get()
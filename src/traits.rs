@@ -132,7 +132,13 @@ where
 
 #[enum_dispatch]
 pub trait HasPosition {
-    /// Position of the object.
+    /// Position of the object, as a native [`Position`].
+    ///
+    /// For room objects, this is built from the packed representation of the
+    /// object's [`RoomPosition`] rather than from its separate `x`, `y`, and
+    /// `roomName` properties.
+    ///
+    /// [`RoomPosition`]: crate::objects::RoomPosition
     fn pos(&self) -> Position;
 }
 
@@ -193,6 +199,24 @@ pub trait RoomObjectProperties {
     fn room(&self) -> Option<Room>;
 }
 
+/// Properties and actions shared by [`Creep`] and [`PowerCreep`], including
+/// movement and resource handling.
+///
+/// Code generic over this trait works with either kind of creep, as well as
+/// with [`MovableObject`].
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::{Direction, ErrorCode, SharedCreepProperties};
+///
+/// fn step_aside(creep: &impl SharedCreepProperties) -> Result<(), ErrorCode> {
+///     creep.say("excuse me", true)?;
+///     creep.move_direction(Direction::Right)
+/// }
+/// ```
+///
+/// [`MovableObject`]: crate::enums::MovableObject
 #[enum_dispatch]
 pub trait SharedCreepProperties {
     /// A shortcut to the part of the `Memory` tree used for this creep by
@@ -329,7 +353,11 @@ pub trait Transferable: AsRef<RoomObject> {}
 pub trait Withdrawable: AsRef<RoomObject> {}
 
 /// Trait for all wrappers over Screeps JavaScript objects which can be the
-/// target of `Creep.harvest`.
+/// target of `Creep.harvest`: [`Source`], [`Mineral`], and [`Deposit`].
+///
+/// [`Source`]: crate::objects::Source
+/// [`Mineral`]: crate::objects::Mineral
+/// [`Deposit`]: crate::objects::Deposit
 ///
 /// # Contracts
 ///
@@ -372,3 +400,30 @@ pub trait Repairable: HasHits + AsRef<Structure> {}
 /// The reference returned from `AsRef<RoomObject>::as_ref` must be a valid
 /// target for `Creep.heal`.
 pub trait Healable: AsRef<RoomObject> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::{Deposit, Mineral, Source};
+
+    fn assert_harvestable<T: Harvestable>() {}
+
+    fn step_aside(creep: &impl SharedCreepProperties) -> Result<(), ErrorCode> {
+        creep.move_direction(Direction::Right)
+    }
+
+    #[test]
+    fn harvest_targets_are_harvestable() {
+        assert_harvestable::<Source>();
+        assert_harvestable::<Mineral>();
+        assert_harvestable::<Deposit>();
+    }
+
+    #[test]
+    fn creep_kinds_share_movement() {
+        // only needs to compile; calling these requires the game environment
+        let _: fn(&Creep) -> Result<(), ErrorCode> = step_aside;
+        let _: fn(&PowerCreep) -> Result<(), ErrorCode> = step_aside;
+        let _: fn(&MovableObject) -> Result<(), ErrorCode> = step_aside;
+    }
+}